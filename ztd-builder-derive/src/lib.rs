@@ -0,0 +1,9 @@
+use proc_macro::TokenStream;
+use ztd_builder_macro::Macro;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[proc_macro_derive(Builder, attributes(Builder))]
+pub fn derive_builder(stream: TokenStream) -> TokenStream {
+    Macro::handle(stream.into()).into()
+}
@@ -0,0 +1,415 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use syn::{parse2, Fields, GenericParam, Generics, Ident, Item, ItemStruct, Visibility};
+use ztd_constructor_macro::{read_visibility, FieldData};
+use ztd_display_macro::write_crate_path;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Data<'a> {
+    ast: &'a ItemStruct,
+    visibility: Visibility,
+    typestate: bool,
+    fields: Vec<FieldData<'a>>,
+}
+
+impl<'a> Data<'a> {
+    fn read(ast: &'a ItemStruct) -> Self {
+        let mut data = Self {
+            ast,
+            visibility: ast.vis.clone(),
+            typestate: false,
+            fields: ast
+                .fields
+                .iter()
+                .enumerate()
+                .map(|(field_index, field)| FieldData::read("Builder", field_index, field))
+                .collect(),
+        };
+
+        if data
+            .fields
+            .iter()
+            .any(|field_data| field_data.validate.is_some())
+        {
+            panic!("Unsupported attribute validate")
+        }
+
+        for attribute in ast
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("Builder"))
+        {
+            let result = attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("visibility") {
+                    data.visibility = read_visibility(&meta)?;
+                } else if meta.path.is_ident("typestate") {
+                    data.typestate = true;
+                } else {
+                    return Err(meta.error("Unknown attribute"));
+                }
+
+                Ok(())
+            });
+
+            if let Err(error) = result {
+                panic!("{}", error)
+            }
+        }
+
+        data
+    }
+
+    fn write(self) -> TokenStream {
+        match self.typestate {
+            true => self.write_typestate(),
+            false => self.write_runtime(),
+        }
+    }
+
+    fn write_initialization(&self) -> TokenStream {
+        let struct_name = &self.ast.ident;
+        let idents = self.fields.iter().map(|field_data| &field_data.ident);
+
+        match self.ast.fields {
+            Fields::Named(_) => quote!(#struct_name { #(#idents),* }),
+            Fields::Unnamed(_) => quote!(#struct_name(#(#idents),*)),
+            Fields::Unit => quote!(#struct_name),
+        }
+    }
+
+    fn write_setter_parameter(field_data: &FieldData) -> (TokenStream, TokenStream) {
+        let r#type = &field_data.field.ty;
+
+        match field_data.into.unwrap_or(true) {
+            true => (
+                quote!(impl ::core::convert::Into<#r#type>),
+                quote!(::core::convert::Into::into(value)),
+            ),
+            false => (quote!(#r#type), quote!(value)),
+        }
+    }
+
+    fn write_runtime(self) -> TokenStream {
+        let crate_path = write_crate_path("ztd-builder");
+        let visibility = &self.visibility;
+        let struct_name = &self.ast.ident;
+        let builder_name = format_ident!("{}Builder", struct_name);
+        let (impl_generics, type_generics, where_clause) = self.ast.generics.split_for_impl();
+        let generics = &self.ast.generics.params;
+
+        let fields = self.fields.iter().map(|field_data| {
+            let ident = &field_data.ident;
+            let r#type = &field_data.field.ty;
+
+            quote!(
+                #ident: ::core::option::Option<#r#type>
+            )
+        });
+
+        let nones = self.fields.iter().map(|field_data| {
+            let ident = &field_data.ident;
+
+            quote!(
+                #ident: ::core::option::Option::None
+            )
+        });
+
+        let setters = self.fields.iter().map(|field_data| {
+            let ident = &field_data.ident;
+            let (parameter, value) = Self::write_setter_parameter(field_data);
+
+            quote!(
+                #visibility fn #ident(mut self, value: #parameter) -> Self {
+                    self.#ident = ::core::option::Option::Some(#value);
+                    self
+                }
+            )
+        });
+
+        let bindings = self.fields.iter().map(|field_data| {
+            let ident = &field_data.ident;
+            let r#type = &field_data.field.ty;
+            let name = ident.to_string();
+
+            let missing = match field_data.write_default() {
+                Some(value) => value,
+                None => quote!(
+                    return ::core::result::Result::Err(#crate_path::MissingFieldError::new(#name))
+                ),
+            };
+
+            quote!(
+                let #ident: #r#type = match self.#ident {
+                    ::core::option::Option::Some(value) => value,
+                    ::core::option::Option::None => #missing,
+                };
+            )
+        });
+
+        let initialization = self.write_initialization();
+
+        quote!(
+            #visibility struct #builder_name <#generics> #where_clause {
+                #(#fields),*
+            }
+
+            impl #impl_generics ::core::default::Default for #builder_name #type_generics #where_clause {
+                fn default() -> Self {
+                    Self {
+                        #(#nones),*
+                    }
+                }
+            }
+
+            impl #impl_generics #struct_name #type_generics #where_clause {
+                #visibility fn builder() -> #builder_name #type_generics {
+                    ::core::default::Default::default()
+                }
+            }
+
+            impl #impl_generics #builder_name #type_generics #where_clause {
+                #(#setters)*
+
+                #visibility fn build(self) -> ::core::result::Result<#struct_name #type_generics, #crate_path::MissingFieldError> {
+                    #(#bindings)*
+
+                    ::core::result::Result::Ok(#initialization)
+                }
+            }
+        )
+    }
+
+    fn write_state(field_index: usize) -> Ident {
+        format_ident!("__State{}", field_index)
+    }
+
+    fn write_builder_type(&self, states: &[TokenStream]) -> TokenStream {
+        let builder_name = format_ident!("{}Builder", self.ast.ident);
+        let arguments = self.ast.generics.params.iter().map(|param| match param {
+            GenericParam::Lifetime(param) => {
+                let lifetime = &param.lifetime;
+                quote!(#lifetime)
+            }
+            GenericParam::Type(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+            GenericParam::Const(param) => {
+                let ident = &param.ident;
+                quote!(#ident)
+            }
+        });
+
+        quote!(#builder_name<#(#arguments,)* #(#states),*>)
+    }
+
+    fn write_generics(&self, field_indices: impl Iterator<Item = usize>) -> Generics {
+        let mut generics = self.ast.generics.clone();
+
+        // The state parameters follow, so defaults would no longer be trailing
+        for param in generics.params.iter_mut() {
+            match param {
+                GenericParam::Type(param) => {
+                    param.eq_token = None;
+                    param.default = None;
+                }
+                GenericParam::Const(param) => {
+                    param.eq_token = None;
+                    param.default = None;
+                }
+                GenericParam::Lifetime(_param) => {}
+            }
+        }
+
+        for field_index in field_indices {
+            let state = Self::write_state(field_index);
+            generics.params.push(parse2(quote!(#state)).unwrap());
+        }
+
+        generics
+    }
+
+    fn write_typestate(self) -> TokenStream {
+        let crate_path = write_crate_path("ztd-builder");
+        let visibility = &self.visibility;
+        let struct_name = &self.ast.ident;
+        let builder_name = format_ident!("{}Builder", struct_name);
+        let (impl_generics, type_generics, where_clause) = self.ast.generics.split_for_impl();
+
+        let generics = self.write_generics(0..self.fields.len());
+        let generics_params = &generics.params;
+
+        let fields = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(field_index, field_data)| {
+                let ident = &field_data.ident;
+                let state = Self::write_state(field_index);
+
+                quote!(
+                    #ident: #state
+                )
+            });
+
+        let unset_states = self
+            .fields
+            .iter()
+            .map(|_| quote!(#crate_path::Unset))
+            .collect::<Vec<_>>();
+        let unset_type = self.write_builder_type(&unset_states);
+
+        let unsets = self.fields.iter().map(|field_data| {
+            let ident = &field_data.ident;
+
+            quote!(
+                #ident: #crate_path::Unset
+            )
+        });
+
+        let setters = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(field_index, field_data)| {
+                let ident = &field_data.ident;
+                let r#type = &field_data.field.ty;
+                let (parameter, value) = Self::write_setter_parameter(field_data);
+
+                let generics = self
+                    .write_generics((0..self.fields.len()).filter(|index| *index != field_index));
+                let (impl_generics, _, where_clause) = generics.split_for_impl();
+
+                let states = |state: TokenStream| {
+                    (0..self.fields.len())
+                        .map(|index| match index == field_index {
+                            true => state.clone(),
+                            false => {
+                                let state = Self::write_state(index);
+                                quote!(#state)
+                            }
+                        })
+                        .collect::<Vec<_>>()
+                };
+
+                let unset_type = self.write_builder_type(&states(quote!(#crate_path::Unset)));
+                let set_type = self.write_builder_type(&states(quote!(#crate_path::Set<#r#type>)));
+
+                let moves = self.fields.iter().map(|other| {
+                    let other = &other.ident;
+
+                    match other == ident {
+                        true => quote!(#other: #crate_path::Set(#value)),
+                        false => quote!(#other: self.#other),
+                    }
+                });
+
+                quote!(
+                    impl #impl_generics #unset_type #where_clause {
+                        #visibility fn #ident(self, value: #parameter) -> #set_type {
+                            #builder_name {
+                                #(#moves,)*
+                                __marker: self.__marker,
+                            }
+                        }
+                    }
+                )
+            });
+
+        let defaulted = self
+            .fields
+            .iter()
+            .enumerate()
+            .filter(|(_, field_data)| field_data.default.is_some())
+            .map(|(field_index, _)| field_index)
+            .collect::<Vec<_>>();
+
+        let mut build_generics = self.write_generics(defaulted.iter().copied());
+        for field_index in defaulted.iter().copied() {
+            let state = Self::write_state(field_index);
+            let r#type = &self.fields[field_index].field.ty;
+
+            build_generics
+                .make_where_clause()
+                .predicates
+                .push(parse2(quote!(#state: #crate_path::State<#r#type>)).unwrap());
+        }
+        let (build_impl_generics, _, build_where_clause) = build_generics.split_for_impl();
+
+        let build_states = self
+            .fields
+            .iter()
+            .enumerate()
+            .map(|(field_index, field_data)| match field_data.default {
+                Some(_) => {
+                    let state = Self::write_state(field_index);
+                    quote!(#state)
+                }
+                None => {
+                    let r#type = &field_data.field.ty;
+                    quote!(#crate_path::Set<#r#type>)
+                }
+            })
+            .collect::<Vec<_>>();
+        let build_type = self.write_builder_type(&build_states);
+
+        let bindings = self.fields.iter().map(|field_data| {
+            let ident = &field_data.ident;
+            let r#type = &field_data.field.ty;
+
+            match field_data.write_default() {
+                Some(default) => quote!(
+                    let #ident: #r#type = match #crate_path::State::<#r#type>::into_option(self.#ident) {
+                        ::core::option::Option::Some(value) => value,
+                        ::core::option::Option::None => #default,
+                    };
+                ),
+                None => quote!(
+                    let #ident: #r#type = self.#ident.0;
+                ),
+            }
+        });
+
+        let initialization = self.write_initialization();
+
+        quote!(
+            #visibility struct #builder_name <#generics_params> #where_clause {
+                #(#fields,)*
+                __marker: ::core::marker::PhantomData<fn() -> #struct_name #type_generics>,
+            }
+
+            impl #impl_generics #struct_name #type_generics #where_clause {
+                #visibility fn builder() -> #unset_type {
+                    #builder_name {
+                        #(#unsets,)*
+                        __marker: ::core::marker::PhantomData,
+                    }
+                }
+            }
+
+            #(#setters)*
+
+            impl #build_impl_generics #build_type #build_where_clause {
+                #visibility fn build(self) -> #struct_name #type_generics {
+                    #(#bindings)*
+
+                    #initialization
+                }
+            }
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct Macro;
+
+impl Macro {
+    pub fn handle(stream: TokenStream) -> TokenStream {
+        match parse2::<Item>(stream).unwrap() {
+            Item::Struct(r#struct) => Data::read(&r#struct).write(),
+            _ => panic!("Unsupported item"),
+        }
+    }
+}
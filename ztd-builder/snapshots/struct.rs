@@ -0,0 +1,56 @@
+pub(crate) struct StructBuilder<T> {
+    first: ::core::option::Option<T>,
+    second: ::core::option::Option<String>,
+    third: ::core::option::Option<u8>,
+}
+impl<T> ::core::default::Default for StructBuilder<T> {
+    fn default() -> Self {
+        Self {
+            first: ::core::option::Option::None,
+            second: ::core::option::Option::None,
+            third: ::core::option::Option::None,
+        }
+    }
+}
+impl<T> Struct<T> {
+    pub(crate) fn builder() -> StructBuilder<T> {
+        ::core::default::Default::default()
+    }
+}
+impl<T> StructBuilder<T> {
+    pub(crate) fn first(mut self, value: impl ::core::convert::Into<T>) -> Self {
+        self.first = ::core::option::Option::Some(::core::convert::Into::into(value));
+        self
+    }
+    pub(crate) fn second(mut self, value: impl ::core::convert::Into<String>) -> Self {
+        self.second = ::core::option::Option::Some(::core::convert::Into::into(value));
+        self
+    }
+    pub(crate) fn third(mut self, value: u8) -> Self {
+        self.third = ::core::option::Option::Some(value);
+        self
+    }
+    pub(crate) fn build(
+        self,
+    ) -> ::core::result::Result<Struct<T>, ::ztd_builder::MissingFieldError> {
+        let first: T = match self.first {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => {
+                return ::core::result::Result::Err(
+                    ::ztd_builder::MissingFieldError::new("first"),
+                );
+            }
+        };
+        let second: String = match self.second {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => {
+                <String as ::core::default::Default>::default()
+            }
+        };
+        let third: u8 = match self.third {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => 1,
+        };
+        ::core::result::Result::Ok(Struct { first, second, third })
+    }
+}
@@ -0,0 +1,55 @@
+pub struct StructBuilder<T, __State0, __State1> {
+    first: __State0,
+    second: __State1,
+    __marker: ::core::marker::PhantomData<fn() -> Struct<T>>,
+}
+impl<T> Struct<T> {
+    pub fn builder() -> StructBuilder<T, ::ztd_builder::Unset, ::ztd_builder::Unset> {
+        StructBuilder {
+            first: ::ztd_builder::Unset,
+            second: ::ztd_builder::Unset,
+            __marker: ::core::marker::PhantomData,
+        }
+    }
+}
+impl<T, __State1> StructBuilder<T, ::ztd_builder::Unset, __State1> {
+    pub fn first(
+        self,
+        value: impl ::core::convert::Into<T>,
+    ) -> StructBuilder<T, ::ztd_builder::Set<T>, __State1> {
+        StructBuilder {
+            first: ::ztd_builder::Set(::core::convert::Into::into(value)),
+            second: self.second,
+            __marker: self.__marker,
+        }
+    }
+}
+impl<T, __State0> StructBuilder<T, __State0, ::ztd_builder::Unset> {
+    pub fn second(
+        self,
+        value: impl ::core::convert::Into<String>,
+    ) -> StructBuilder<T, __State0, ::ztd_builder::Set<String>> {
+        StructBuilder {
+            first: self.first,
+            second: ::ztd_builder::Set(::core::convert::Into::into(value)),
+            __marker: self.__marker,
+        }
+    }
+}
+impl<T, __State1> StructBuilder<T, ::ztd_builder::Set<T>, __State1>
+where
+    __State1: ::ztd_builder::State<String>,
+{
+    pub fn build(self) -> Struct<T> {
+        let first: T = self.first.0;
+        let second: String = match ::ztd_builder::State::<
+            String,
+        >::into_option(self.second) {
+            ::core::option::Option::Some(value) => value,
+            ::core::option::Option::None => {
+                <String as ::core::default::Default>::default()
+            }
+        };
+        Struct { first, second }
+    }
+}
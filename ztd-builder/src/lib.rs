@@ -0,0 +1,63 @@
+#![no_std]
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+use core::error::Error;
+use core::fmt::{self, Display, Formatter};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub use ztd_builder_derive::Builder;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Returned by a generated `build()` when a required field was never set.
+#[derive(Clone, Copy, Debug, Eq, PartialEq)]
+pub struct MissingFieldError {
+    field: &'static str,
+}
+
+impl MissingFieldError {
+    pub const fn new(field: &'static str) -> Self {
+        Self { field }
+    }
+
+    pub const fn field(&self) -> &'static str {
+        self.field
+    }
+}
+
+impl Display for MissingFieldError {
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        write!(formatter, "Missing field {}", self.field)
+    }
+}
+
+impl Error for MissingFieldError {}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Typestate of a field that was not set yet.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Unset;
+
+/// Typestate of a field that holds its value.
+#[derive(Clone, Copy, Debug, Default, Eq, PartialEq)]
+pub struct Set<T>(pub T);
+
+/// Implemented by both typestates, used by `build()` for fields with a default.
+pub trait State<T> {
+    fn into_option(self) -> Option<T>;
+}
+
+impl<T> State<T> for Unset {
+    fn into_option(self) -> Option<T> {
+        None
+    }
+}
+
+impl<T> State<T> for Set<T> {
+    fn into_option(self) -> Option<T> {
+        Some(self.0)
+    }
+}
@@ -0,0 +1,271 @@
+use quote::quote;
+use trybuild::TestCases;
+use ztd_builder::{Builder, MissingFieldError};
+use ztd_builder_macro::Macro;
+use ztd_test::assert_expansion;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn r#struct() {
+    #[derive(Builder)]
+    struct Struct {
+        first: String,
+        second: usize,
+    }
+
+    let value = Struct::builder()
+        .first("foo")
+        .second(1usize)
+        .build()
+        .unwrap();
+
+    assert!(value.first == "foo");
+    assert!(value.second == 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_missing_field() {
+    #[derive(Builder)]
+    struct Struct {
+        _first: String,
+        _second: usize,
+    }
+
+    assert!(
+        Struct::builder()._second(1usize).build().err() == Some(MissingFieldError::new("_first"))
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_defaults() {
+    #[derive(Builder)]
+    struct Struct {
+        first: String,
+        #[Builder(default)]
+        second: Vec<u8>,
+        #[Builder(default = first.len())]
+        third: usize,
+    }
+
+    let value = Struct::builder().first("foo").build().unwrap();
+
+    assert!(value.first == "foo");
+    assert!(value.second.is_empty());
+    assert!(value.third == 3);
+
+    let value = Struct::builder()
+        .first("foo")
+        .second(vec![1])
+        .third(1usize)
+        .build()
+        .unwrap();
+
+    assert!(value.second == vec![1]);
+    assert!(value.third == 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct() {
+    #[derive(Builder)]
+    struct TupleStruct(String, #[Builder(default)] usize);
+
+    let value = TupleStruct::builder().value0("foo").build().unwrap();
+
+    assert!(value.0 == "foo");
+    assert!(value.1 == 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_generics() {
+    #[derive(Builder)]
+    struct Struct<'a, T>
+    where
+        T: Clone,
+    {
+        first: &'a T,
+        #[Builder(into = false)]
+        second: Option<T>,
+    }
+
+    let first = 1;
+    let value = Struct::builder()
+        .first(&first)
+        .second(Some(2))
+        .build()
+        .unwrap();
+
+    assert!(*value.first == 1);
+    assert!(value.second == Some(2));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_marker() {
+    #[derive(Builder)]
+    #[Builder(typestate)]
+    struct Struct<T> {
+        first: usize,
+        _second: core::marker::PhantomData<T>,
+    }
+
+    assert!(Struct::<String>::builder().first(1usize).build().first == 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn typestate_struct() {
+    #[derive(Builder)]
+    #[Builder(typestate)]
+    struct Struct {
+        first: String,
+        #[Builder(default = first.len())]
+        second: usize,
+        third: u8,
+    }
+
+    let value = Struct::builder().third(1).first("foo").build();
+
+    assert!(value.first == "foo");
+    assert!(value.second == 3);
+    assert!(value.third == 1);
+
+    let value = Struct::builder()
+        .second(1usize)
+        .first("foo")
+        .third(2)
+        .build();
+
+    assert!(value.second == 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn typestate_tuple_struct_with_generics() {
+    #[derive(Builder)]
+    #[Builder(typestate)]
+    struct TupleStruct<'a, T, const N: usize>(&'a [T; N], #[Builder(default)] Option<T>)
+    where
+        T: Copy;
+
+    let array = [1, 2];
+    let value = TupleStruct::builder().value0(&array).build();
+
+    assert!(value.0 == &[1, 2]);
+    assert!(value.1.is_none());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn typestate_struct_with_generic_defaults() {
+    #[derive(Builder)]
+    #[Builder(typestate)]
+    struct Struct<T = u32, const N: usize = 2> {
+        first: [T; N],
+    }
+
+    let value: Struct = Struct::builder().first([1, 2]).build();
+
+    assert!(value.first == [1, 2]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn ui() {
+    let cases = TestCases::new();
+
+    cases.compile_fail("ui/typestate_missing_field.rs");
+    cases.compile_fail("ui/typestate_set_twice.rs");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unsupported item")]
+fn enum_should_panic() {
+    Macro::handle(quote!(
+        #[derive(Builder)]
+        enum Enum {
+            First,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown attribute")]
+fn unknown_attribute_should_panic() {
+    Macro::handle(quote!(
+        #[derive(Builder)]
+        #[Builder(unknown)]
+        struct Struct {
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unsupported attribute validate")]
+fn validate_should_panic() {
+    Macro::handle(quote!(
+        #[derive(Builder)]
+        struct Struct {
+            #[Builder(validate = validate)]
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct() {
+    assert_expansion(
+        "struct",
+        Macro::handle(quote!(
+            #[derive(Builder)]
+            #[Builder(visibility = pub(crate))]
+            pub struct Struct<T> {
+                first: T,
+                #[Builder(default)]
+                second: String,
+                #[Builder(into = false, default = 1)]
+                third: u8,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_typestate_struct() {
+    assert_expansion(
+        "typestate_struct",
+        Macro::handle(quote!(
+            #[derive(Builder)]
+            #[Builder(typestate)]
+            pub struct Struct<T> {
+                first: T,
+                #[Builder(default)]
+                second: String,
+            }
+        )),
+    );
+}
@@ -0,0 +1,16 @@
+use ztd_builder::Builder;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Builder)]
+#[Builder(typestate)]
+pub struct Struct {
+    first: usize,
+    second: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn main() {
+    let _ = Struct::builder().first(1usize).build();
+}
@@ -0,0 +1,15 @@
+use ztd_builder::Builder;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Builder)]
+#[Builder(typestate)]
+pub struct Struct {
+    first: usize,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn main() {
+    let _ = Struct::builder().first(1usize).first(2usize).build();
+}
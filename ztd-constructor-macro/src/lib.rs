@@ -1,184 +1,821 @@
 use proc_macro2::TokenStream;
 use quote::{format_ident, quote};
-use std::collections::HashSet;
-use syn::{parse2, Field, Fields, ItemStruct, Visibility};
+use syn::meta::ParseNestedMeta;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::{
+    parenthesized, parse2, parse_str, Attribute, Error, Expr, ExprPath, Field, Fields, Ident,
+    Index, Item, ItemEnum, ItemStruct, LitBool, LitStr, Member, PathArguments, Result, Token, Type,
+    Variant, Visibility,
+};
+use ztd_display_macro::write_crate_path;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-struct Data<'a> {
-    ast: &'a ItemStruct,
-    visibility: Option<Visibility>,
-    default_fields: HashSet<&'a Field>,
+pub enum FieldDataDefault {
+    Default,
+    Value(Expr),
+    Marker,
 }
 
-impl<'a> Data<'a> {
-    fn read(ast: &'a ItemStruct) -> Self {
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct FieldData<'a> {
+    pub field: &'a Field,
+    pub member: Member,
+    pub ident: Ident,
+    pub default: Option<FieldDataDefault>,
+    pub into: Option<bool>,
+    pub validate: Option<ExprPath>,
+}
+
+impl<'a> FieldData<'a> {
+    pub fn read(attribute: &str, field_index: usize, field: &'a Field) -> Self {
+        let mut data = Self {
+            field,
+            member: match &field.ident {
+                Some(ident) => Member::Named(ident.clone()),
+                None => Member::Unnamed(Index::from(field_index)),
+            },
+            ident: match &field.ident {
+                Some(ident) => ident.clone(),
+                None => format_ident!("value{}", field_index),
+            },
+            default: None,
+            into: None,
+            validate: None,
+        };
+
+        let mut marker = None;
+
+        for other in field
+            .attrs
+            .iter()
+            .filter(|other| other.path().is_ident(attribute))
+        {
+            let result = other.parse_nested_meta(|meta| {
+                if meta.path.is_ident("default") {
+                    data.default = match meta.input.peek(Token![=]) {
+                        true => Some(FieldDataDefault::Value(meta.value()?.parse()?)),
+                        false => Some(FieldDataDefault::Default),
+                    };
+                } else if meta.path.is_ident("into") {
+                    data.into = match meta.input.peek(Token![=]) {
+                        true => Some(meta.value()?.parse::<LitBool>()?.value),
+                        false => Some(true),
+                    };
+                } else if meta.path.is_ident("validate") {
+                    data.validate = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("marker") {
+                    marker = match meta.input.peek(Token![=]) {
+                        true => Some(meta.value()?.parse::<LitBool>()?.value),
+                        false => Some(true),
+                    };
+                } else {
+                    return Err(meta.error("Unknown attribute"));
+                }
+
+                Ok(())
+            });
+
+            if let Err(error) = result {
+                panic!("{}", error)
+            }
+        }
+
+        if data.default.is_none() && marker.unwrap_or_else(|| is_marker(&field.ty)) {
+            data.default = Some(FieldDataDefault::Marker);
+        }
+
+        data
+    }
+
+    pub fn write_default(&self) -> Option<TokenStream> {
+        let r#type = &self.field.ty;
+
+        match self.default.as_ref()? {
+            FieldDataDefault::Default => {
+                Some(quote!(<#r#type as ::core::default::Default>::default()))
+            }
+            FieldDataDefault::Value(value) => Some(quote!(#value)),
+            FieldDataDefault::Marker => Some(write_marker(r#type)),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// Only `()`, `PhantomData` and `PhantomPinned` are detected, a unit struct can't be told apart
+// from any other type by its name and stays a parameter unless opted in with `marker`
+fn is_marker(r#type: &Type) -> bool {
+    match r#type {
+        Type::Tuple(tuple) => tuple.elems.is_empty(),
+        Type::Path(path) if path.qself.is_none() => {
+            path.path.segments.last().is_some_and(|segment| {
+                segment.ident == "PhantomData" || segment.ident == "PhantomPinned"
+            })
+        }
+        Type::Paren(paren) => is_marker(&paren.elem),
+        Type::Group(group) => is_marker(&group.elem),
+        _ => false,
+    }
+}
+
+// Unit structs can be used as values, their generic arguments only need a turbofish
+fn write_marker(r#type: &Type) -> TokenStream {
+    match r#type {
+        Type::Tuple(tuple) if tuple.elems.is_empty() => quote!(()),
+        Type::Path(path) if path.qself.is_none() => {
+            let mut path = path.path.clone();
+
+            for segment in path.segments.iter_mut() {
+                if let PathArguments::AngleBracketed(arguments) = &mut segment.arguments {
+                    arguments.colon2_token = Some(Default::default());
+                }
+            }
+
+            quote!(#path)
+        }
+        Type::Paren(paren) => write_marker(&paren.elem),
+        Type::Group(group) => write_marker(&group.elem),
+        _ => Error::new_spanned(r#type, "Unsupported marker type").to_compile_error(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn read_visibility(meta: &ParseNestedMeta) -> Result<Visibility> {
+    let visibility = meta.value()?.parse::<Visibility>().unwrap();
+
+    // Visibility::Inherited catches everything when being parsed
+    if matches!(visibility, Visibility::Inherited) {
+        return Err(meta.error("Unknown visibility"));
+    }
+
+    Ok(visibility)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+enum Wrap {
+    Arc,
+    Rc,
+    Box,
+}
+
+impl Wrap {
+    fn read(ident: &Ident) -> Result<Self> {
+        if ident == "Arc" {
+            Ok(Self::Arc)
+        } else if ident == "Rc" {
+            Ok(Self::Rc)
+        } else if ident == "Box" {
+            Ok(Self::Box)
+        } else {
+            Err(Error::new_spanned(
+                ident,
+                "Unknown wrap, expected `Arc`, `Rc` or `Box`",
+            ))
+        }
+    }
+
+    fn suffix(&self) -> &'static str {
+        match self {
+            Self::Arc => "arc",
+            Self::Rc => "rc",
+            Self::Box => "boxed",
+        }
+    }
+
+    fn path(&self) -> TokenStream {
+        let crate_path = write_crate_path("ztd-constructor");
+
+        match self {
+            Self::Arc => quote!(#crate_path::__private::Arc),
+            Self::Rc => quote!(#crate_path::__private::Rc),
+            Self::Box => quote!(#crate_path::__private::Box),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct ConstructorData {
+    name: Ident,
+    fields: Option<Vec<Member>>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Options {
+    visibility: Visibility,
+    into: bool,
+    r#const: bool,
+    validate: Option<ExprPath>,
+    error: Option<Type>,
+    unchecked: bool,
+    wraps: Vec<Wrap>,
+    constructors: Vec<ConstructorData>,
+}
+
+impl Options {
+    fn read(attributes: &[Attribute], visibility: &Visibility) -> Self {
+        let mut options = Self {
+            visibility: visibility.clone(),
+            into: false,
+            r#const: false,
+            validate: None,
+            error: None,
+            unchecked: false,
+            wraps: Vec::new(),
+            constructors: Vec::new(),
+        };
+
+        for attribute in attributes
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("Constructor"))
+        {
+            let mut name = None;
+            let mut fields = None;
+
+            let result = attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("visibility") {
+                    options.visibility = read_visibility(&meta)?;
+                } else if meta.path.is_ident("into") {
+                    options.into = true;
+                } else if meta.path.is_ident("const") {
+                    options.r#const = true;
+                } else if meta.path.is_ident("validate") {
+                    options.validate = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("error") {
+                    options.error = Some(meta.value()?.parse()?);
+                } else if meta.path.is_ident("unchecked") {
+                    options.unchecked = true;
+                } else if meta.path.is_ident("wrap") {
+                    if meta.input.peek(Token![=]) {
+                        options.wraps.push(Wrap::read(&meta.value()?.parse()?)?);
+                    } else {
+                        let content;
+                        parenthesized!(content in meta.input);
+
+                        for ident in Punctuated::<Ident, Comma>::parse_terminated(&content)? {
+                            options.wraps.push(Wrap::read(&ident)?);
+                        }
+                    }
+                } else if meta.path.is_ident("name") {
+                    name = Some(meta.value()?.parse::<LitStr>()?.parse::<Ident>()?);
+                } else if meta.path.is_ident("fields") {
+                    let content;
+                    parenthesized!(content in meta.input);
+
+                    fields = Some(
+                        Punctuated::<Member, Comma>::parse_terminated(&content)?
+                            .into_iter()
+                            .collect(),
+                    );
+                } else {
+                    return Err(meta.error("Unknown attribute"));
+                }
+
+                Ok(())
+            });
+
+            if let Err(error) = result {
+                panic!("{}", error)
+            }
+
+            match (name, fields) {
+                (Some(name), fields) => options.constructors.push(ConstructorData { name, fields }),
+                (None, Some(_fields)) => panic!("Missing constructor name"),
+                (None, None) => {}
+            }
+        }
+
+        options
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct FieldsData<'a> {
+    ident: &'a Ident,
+    fields: &'a Fields,
+    r#impl: TokenStream,
+    data: Vec<FieldData<'a>>,
+}
+
+impl<'a> FieldsData<'a> {
+    fn read(ident: &'a Ident, fields: &'a Fields, r#impl: TokenStream) -> Self {
         Self {
-            ast,
-            visibility: ast
-                .attrs
+            ident,
+            fields,
+            r#impl,
+            data: fields
                 .iter()
-                .find(|attribute| attribute.path().is_ident("Constructor"))
-                .and_then(|attribute| {
-                    let mut visibility = None;
+                .enumerate()
+                .map(|(field_index, field)| FieldData::read("Constructor", field_index, field))
+                .collect(),
+        }
+    }
 
-                    let result = attribute.parse_nested_meta(|meta| {
-                        if meta.path.is_ident("visibility") {
-                            let value = meta.value()?;
+    fn required(&self) -> Vec<usize> {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(_field_index, field_data)| field_data.default.is_none())
+            .map(|(field_index, _field_data)| field_index)
+            .collect()
+    }
 
-                            visibility = Some(value.parse::<Visibility>().unwrap());
+    fn position(&self, member: &Member) -> usize {
+        match self
+            .data
+            .iter()
+            .position(|field_data| field_data.member == *member)
+        {
+            Some(field_index) => field_index,
+            None => panic!("Unknown field {}", quote!(#member)),
+        }
+    }
 
-                            // Visibility::Inherited catches everything when being parsed
-                            if matches!(visibility, Some(Visibility::Inherited)) {
-                                return Err(meta.error("Unknown visibility"));
-                            }
+    fn is_validated(&self, options: &Options) -> bool {
+        let validated = options.validate.is_some()
+            || self
+                .data
+                .iter()
+                .any(|field_data| field_data.validate.is_some());
 
-                            Ok(())
-                        } else {
-                            Err(meta.error("Unknown attribute"))
-                        }
-                    });
+        if validated && options.error.is_none() {
+            panic!("Missing error type for validation")
+        }
+
+        validated
+    }
 
-                    if let Err(error) = result {
-                        panic!("{}", error)
+    fn is_into(&self, options: &Options, field_data: &FieldData) -> bool {
+        field_data.into.unwrap_or(options.into)
+    }
+
+    fn write_const_errors(&self, options: &Options, arguments: &[usize]) -> Option<TokenStream> {
+        let errors = self
+            .data
+            .iter()
+            .enumerate()
+            .flat_map(|(field_index, field_data)| {
+                if arguments.contains(&field_index) {
+                    match self.is_into(options, field_data) {
+                        true => Some(Error::new_spanned(
+                            field_data.field,
+                            "`into` arguments are not supported in `const` constructors",
+                        )),
+                        false => None,
+                    }
+                } else {
+                    match field_data.default {
+                        Some(FieldDataDefault::Default) => Some(Error::new_spanned(
+                            field_data.field,
+                            "`Default::default()` is not const, use `#[Constructor(default = ...)]` with a const expression",
+                        )),
+                        _ => None,
                     }
+                }
+            })
+            .reduce(|mut error, other| {
+                error.combine(other);
+                error
+            })?;
+
+        Some(errors.to_compile_error())
+    }
 
-                    visibility
-                }),
-            default_fields: ast
-                .fields
+    fn write_constructor(
+        &self,
+        options: &Options,
+        name: &Ident,
+        arguments: &[usize],
+        validated: bool,
+    ) -> TokenStream {
+        let constructor = self.write_unwrapped_constructor(options, name, arguments, validated);
+        let visibility = &options.visibility;
+
+        let wrapped_constructors = options.wraps.iter().map(|wrap| {
+            let wrapped_name = format_ident!("{}_{}", name, wrap.suffix());
+            let path = wrap.path();
+            let parameters = self.write_arguments(options, arguments);
+            let idents = arguments
                 .iter()
-                .filter(|field| {
-                    let mut default = false;
-
-                    for attribute in field
-                        .attrs
-                        .iter()
-                        .filter(|attribute| attribute.path().is_ident("Constructor"))
-                    {
-                        let result = attribute.parse_nested_meta(|meta| {
-                            if meta.path.is_ident("default") {
-                                default = true;
-                            } else {
-                                return Err(meta.error("Unknown attribute"));
-                            }
-
-                            Ok(())
-                        });
-
-                        if let Err(error) = result {
-                            panic!("{}", error)
+                .map(|field_index| &self.data[*field_index].ident);
+
+            match validated {
+                true => {
+                    let error = &options.error;
+
+                    quote!(
+                        #visibility fn #wrapped_name(#(#parameters),*) -> ::core::result::Result<#path<Self>, #error> {
+                            ::core::result::Result::map(Self::#name(#(#idents),*), #path::new)
                         }
+                    )
+                }
+                false => quote!(
+                    #visibility fn #wrapped_name(#(#parameters),*) -> #path<Self> {
+                        #path::new(Self::#name(#(#idents),*))
                     }
+                ),
+            }
+        });
 
-                    default
-                })
-                .collect(),
+        quote!(
+            #constructor
+
+            #(#wrapped_constructors)*
+        )
+    }
+
+    fn write_unwrapped_constructor(
+        &self,
+        options: &Options,
+        name: &Ident,
+        arguments: &[usize],
+        validated: bool,
+    ) -> TokenStream {
+        let visibility = &options.visibility;
+
+        let r#const = match options.r#const {
+            true => {
+                if validated {
+                    return Error::new_spanned(
+                        self.ident,
+                        "validation is not supported in `const` constructors",
+                    )
+                    .to_compile_error();
+                }
+
+                if let Some(errors) = self.write_const_errors(options, arguments) {
+                    return errors;
+                }
+
+                quote!(const)
+            }
+            false => quote!(),
+        };
+
+        let parameters = self.write_arguments(options, arguments);
+        let conversions = self.write_conversions(options, arguments);
+        let defaults = self.write_defaults(arguments);
+        let initialization = self.write_initialization();
+
+        if !validated {
+            return quote!(
+                #visibility #r#const fn #name(#(#parameters),*) -> Self {
+                    #(#conversions)*
+                    #(#defaults)*
+
+                    #initialization
+                }
+            );
         }
+
+        let error = &options.error;
+
+        let field_validations = self.data.iter().flat_map(|field_data| {
+            let ident = &field_data.ident;
+            let validate = field_data.validate.as_ref()?;
+
+            Some(quote!(
+                #validate(&#ident)?;
+            ))
+        });
+
+        let validation = options.validate.as_ref().map(|validate| {
+            quote!(
+                #validate(&value)?;
+            )
+        });
+
+        quote!(
+            #visibility fn #name(#(#parameters),*) -> ::core::result::Result<Self, #error> {
+                #(#conversions)*
+                #(#defaults)*
+                #(#field_validations)*
+
+                let value = #initialization;
+
+                #validation
+
+                ::core::result::Result::Ok(value)
+            }
+        )
     }
 
-    fn write(self) -> TokenStream {
-        let visibility = self.visibility.as_ref().or(Some(&self.ast.vis));
-
-        let constructor = match self.ast.fields {
-            Fields::Named(ref fields) => {
-                let arguments = self.write_arguments(
-                    fields
-                        .named
-                        .iter()
-                        .filter(|field| !self.default_fields.contains(field)),
-                );
-                let initializations = self.write_initializations(fields.named.iter());
+    fn write_arguments<'b>(
+        &'b self,
+        options: &'b Options,
+        arguments: &'b [usize],
+    ) -> impl Iterator<Item = TokenStream> + 'b {
+        arguments.iter().map(|field_index| {
+            let field_data = &self.data[*field_index];
+            let ident = &field_data.ident;
+            let r#type = &field_data.field.ty;
 
-                quote!(
-                    #visibility fn new(#(#arguments),*) -> Self {
-                        Self {
-                            #(#initializations),*
-                        }
-                    }
-                )
+            match self.is_into(options, field_data) {
+                true => quote!(
+                    #ident: impl ::core::convert::Into<#r#type>
+                ),
+                false => quote!(
+                    #ident: #r#type
+                ),
             }
-            Fields::Unnamed(ref fields) => {
-                let arguments = self.write_arguments(
-                    fields
-                        .unnamed
-                        .iter()
-                        .filter(|field| !self.default_fields.contains(field)),
-                );
-                let initializations = self.write_initializations(fields.unnamed.iter());
+        })
+    }
+
+    fn write_conversions<'b>(
+        &'b self,
+        options: &'b Options,
+        arguments: &'b [usize],
+    ) -> impl Iterator<Item = TokenStream> + 'b {
+        arguments
+            .iter()
+            .map(|field_index| &self.data[*field_index])
+            .filter(|field_data| self.is_into(options, field_data))
+            .map(|field_data| {
+                let ident = &field_data.ident;
+                let r#type = &field_data.field.ty;
 
                 quote!(
-                    #visibility fn new(#(#arguments),*) -> Self {
-                        Self(#(#initializations),*)
-                    }
+                    let #ident: #r#type = ::core::convert::Into::into(#ident);
                 )
-            }
-            Fields::Unit => {
+            })
+    }
+
+    fn write_defaults<'b>(
+        &'b self,
+        arguments: &'b [usize],
+    ) -> impl Iterator<Item = TokenStream> + 'b {
+        self.data
+            .iter()
+            .enumerate()
+            .filter(|(field_index, _field_data)| !arguments.contains(field_index))
+            .map(|(_field_index, field_data)| {
+                let ident = &field_data.ident;
+                let r#type = &field_data.field.ty;
+
+                let value = match field_data.write_default() {
+                    Some(value) => value,
+                    None => panic!("Missing default for field {}", ident),
+                };
+
                 quote!(
-                    #visibility fn new() -> Self {
-                        Self
-                    }
+                    let #ident: #r#type = #value;
                 )
-            }
+            })
+    }
+
+    fn write_initialization(&self) -> TokenStream {
+        let r#impl = &self.r#impl;
+        let idents = self.data.iter().map(|field_data| &field_data.ident);
+
+        match self.fields {
+            Fields::Named(_) => quote!(#r#impl { #(#idents),* }),
+            Fields::Unnamed(_) => quote!(#r#impl(#(#idents),*)),
+            Fields::Unit => quote!(#r#impl),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct StructData<'a> {
+    ast: &'a ItemStruct,
+    options: Options,
+    fields: FieldsData<'a>,
+}
+
+impl<'a> StructData<'a> {
+    fn read(ast: &'a ItemStruct) -> Self {
+        Self {
+            ast,
+            options: Options::read(&ast.attrs, &ast.vis),
+            fields: FieldsData::read(&ast.ident, &ast.fields, quote!(Self)),
+        }
+    }
+
+    fn write(self) -> TokenStream {
+        let required = self.fields.required();
+        let validated = self.fields.is_validated(&self.options);
+
+        let default_name = match validated {
+            true => format_ident!("try_new"),
+            false => format_ident!("new"),
+        };
+
+        let new = match self
+            .options
+            .constructors
+            .iter()
+            .any(|constructor| constructor.name == default_name)
+        {
+            true => None,
+            false => Some(self.fields.write_constructor(
+                &self.options,
+                &default_name,
+                &required,
+                validated,
+            )),
+        };
+
+        let unchecked_name = format_ident!("new");
+
+        if validated
+            && self.options.unchecked
+            && self
+                .options
+                .constructors
+                .iter()
+                .any(|constructor| constructor.name == unchecked_name)
+        {
+            panic!("Named constructor new clashes with the unchecked constructor, rename it")
+        }
+
+        let unchecked = match validated && self.options.unchecked {
+            true => Some(self.fields.write_constructor(
+                &self.options,
+                &unchecked_name,
+                &required,
+                false,
+            )),
+            false => None,
         };
 
+        let constructors = self.options.constructors.iter().map(|constructor| {
+            let arguments = match &constructor.fields {
+                Some(fields) => fields
+                    .iter()
+                    .map(|member| self.fields.position(member))
+                    .collect(),
+                None => required.clone(),
+            };
+
+            self.fields
+                .write_constructor(&self.options, &constructor.name, &arguments, validated)
+        });
+
         let struct_name = &self.ast.ident;
         let (impl_generics, type_generics, where_clause) = self.ast.generics.split_for_impl();
 
         quote!(
             impl #impl_generics #struct_name #type_generics #where_clause {
-                #constructor
+                #new
+
+                #unchecked
+
+                #(#constructors)*
             }
         )
     }
+}
 
-    fn write_arguments<'b, T>(&self, iterator: T) -> impl Iterator<Item = TokenStream> + 'b
-    where
-        T: Iterator<Item = &'b Field> + 'b,
-    {
-        iterator.enumerate().map(|(index, field)| {
-            let ident = match &field.ident {
-                Some(ident) => ident.clone(),
-                None => format_ident!("value{}", index),
-            };
+////////////////////////////////////////////////////////////////////////////////////////////////////
 
-            let r#type = &field.ty;
+fn write_snake_case(ident: &Ident) -> Ident {
+    let name = ident.to_string();
+    let characters = name.chars().collect::<Vec<_>>();
+    let mut snake_case = String::with_capacity(name.len() + 4);
 
-            quote!(
-                #ident: #r#type
-            )
-        })
+    for (index, character) in characters.iter().enumerate() {
+        if character.is_uppercase() && index > 0 {
+            let previous = characters[index - 1];
+            let next = characters.get(index + 1);
+
+            if previous.is_lowercase()
+                || previous.is_ascii_digit()
+                || (previous.is_uppercase() && next.is_some_and(|next| next.is_lowercase()))
+            {
+                snake_case.push('_');
+            }
+        }
+
+        snake_case.extend(character.to_lowercase());
     }
 
-    fn write_initializations<T>(&'a self, iterator: T) -> impl Iterator<Item = TokenStream> + 'a
-    where
-        T: Iterator<Item = &'a Field> + 'a,
-    {
-        iterator
-            .filter(|field| !self.default_fields.contains(field))
-            .enumerate()
-            .map(|(index, field)| {
-                let ident = match &field.ident {
-                    Some(ident) => ident.clone(),
-                    None => format_ident!("value{}", index),
-                };
+    match parse_str::<Ident>(&snake_case) {
+        Ok(snake_case) => Ident::new(&snake_case.to_string(), ident.span()),
+        Err(_error) => Ident::new_raw(&snake_case, ident.span()),
+    }
+}
 
-                quote!(
-                    #ident
-                )
-            })
-            .chain(self.default_fields.iter().map(|field| {
-                let field_type = &field.ty;
-
-                match &field.ident {
-                    Some(ident) => quote!(
-                        #ident: <#field_type as ::core::default::Default>::default()
-                    ),
-                    None => quote!(
-                        <#field_type as ::core::default::Default>::default()
-                    ),
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct EnumVariantData<'a> {
+    name: Ident,
+    skip: bool,
+    fields: FieldsData<'a>,
+}
+
+impl<'a> EnumVariantData<'a> {
+    fn read(variant: &'a Variant) -> Self {
+        let variant_ident = &variant.ident;
+
+        let mut data = Self {
+            name: write_snake_case(variant_ident),
+            skip: false,
+            fields: FieldsData::read(variant_ident, &variant.fields, quote!(Self::#variant_ident)),
+        };
+
+        for attribute in variant
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("Constructor"))
+        {
+            let result = attribute.parse_nested_meta(|meta| {
+                if meta.path.is_ident("skip") {
+                    data.skip = true;
+                } else if meta.path.is_ident("name") {
+                    data.name = meta.value()?.parse::<LitStr>()?.parse()?;
+                } else {
+                    return Err(meta.error("Unknown attribute"));
                 }
-            }))
+
+                Ok(())
+            });
+
+            if let Err(error) = result {
+                panic!("{}", error)
+            }
+        }
+
+        data
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct EnumData<'a> {
+    ast: &'a ItemEnum,
+    options: Options,
+    variants: Vec<EnumVariantData<'a>>,
+}
+
+impl<'a> EnumData<'a> {
+    fn read(ast: &'a ItemEnum) -> Self {
+        let options = Options::read(&ast.attrs, &ast.vis);
+
+        if !options.constructors.is_empty() {
+            panic!("Unsupported named constructors for enums")
+        }
+
+        Self {
+            ast,
+            options,
+            variants: ast.variants.iter().map(EnumVariantData::read).collect(),
+        }
+    }
+
+    fn write(self) -> TokenStream {
+        let constructors = self
+            .variants
+            .iter()
+            .filter(|variant_data| !variant_data.skip)
+            .map(|variant_data| {
+                variant_data.fields.write_constructor(
+                    &self.options,
+                    &variant_data.name,
+                    &variant_data.fields.required(),
+                    variant_data.fields.is_validated(&self.options),
+                )
+            });
+
+        let enum_name = &self.ast.ident;
+        let (impl_generics, type_generics, where_clause) = self.ast.generics.split_for_impl();
+
+        quote!(
+            impl #impl_generics #enum_name #type_generics #where_clause {
+                #(#constructors)*
+            }
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+enum Data<'a> {
+    Enum(EnumData<'a>),
+    Struct(StructData<'a>),
+}
+
+impl<'a> Data<'a> {
+    fn read(item: &'a Item) -> Self {
+        match item {
+            Item::Enum(r#enum) => Self::Enum(EnumData::read(r#enum)),
+            Item::Struct(r#struct) => Self::Struct(StructData::read(r#struct)),
+            _ => panic!("Unsupported item"),
+        }
+    }
+
+    fn write(self) -> TokenStream {
+        match self {
+            Self::Enum(data) => data.write(),
+            Self::Struct(data) => data.write(),
+        }
     }
 }
 
@@ -188,6 +825,6 @@ pub struct Macro;
 
 impl Macro {
     pub fn handle(stream: TokenStream) -> TokenStream {
-        Data::read(&parse2::<ItemStruct>(stream).unwrap()).write()
+        Data::read(&parse2::<Item>(stream).unwrap()).write()
     }
 }
@@ -0,0 +1,6 @@
+impl TupleStruct {
+    pub const fn new(value0: u8) -> Self {
+        let value1: usize = 42;
+        Self(value0, value1)
+    }
+}
@@ -0,0 +1,15 @@
+impl<T> Shape<T> {
+    pub fn circle(radius: T) -> Self {
+        Self::Circle { radius }
+    }
+    pub fn rect(value0: T, value1: T) -> Self {
+        Self::Rect(value0, value1)
+    }
+    pub fn empty() -> Self {
+        Self::Empty
+    }
+    pub fn r#type() -> Self {
+        let value0: u8 = 1;
+        Self::Type(value0)
+    }
+}
@@ -0,0 +1,6 @@
+impl<'a> Struct<'a> {
+    pub(crate) fn new(first: &'a str) -> Self {
+        let second: usize = <usize as ::core::default::Default>::default();
+        Self { first, second }
+    }
+}
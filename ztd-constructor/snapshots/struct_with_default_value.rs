@@ -0,0 +1,6 @@
+impl Struct {
+    fn new(items: Vec<usize>) -> Self {
+        let length: usize = items.len();
+        Self { items, length }
+    }
+}
@@ -0,0 +1,7 @@
+impl Config {
+    fn new(name: impl ::core::convert::Into<String>, retries: u8) -> Self {
+        let name: String = ::core::convert::Into::into(name);
+        let length: usize = name.len();
+        Self { name, retries, length }
+    }
+}
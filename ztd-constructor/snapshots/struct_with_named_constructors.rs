@@ -0,0 +1,16 @@
+impl Buffer {
+    fn new() -> Self {
+        let data: Vec<u8> = <Vec<u8> as ::core::default::Default>::default();
+        let capacity: usize = 16;
+        Self { data, capacity }
+    }
+    fn empty() -> Self {
+        let data: Vec<u8> = <Vec<u8> as ::core::default::Default>::default();
+        let capacity: usize = 16;
+        Self { data, capacity }
+    }
+    fn with_capacity(capacity: usize) -> Self {
+        let data: Vec<u8> = <Vec<u8> as ::core::default::Default>::default();
+        Self { data, capacity }
+    }
+}
@@ -0,0 +1,12 @@
+impl Range {
+    fn try_new(
+        name: String,
+        start: usize,
+        end: usize,
+    ) -> ::core::result::Result<Self, Error> {
+        validate_name(&name)?;
+        let value = Self { name, start, end };
+        Self::validate(&value)?;
+        ::core::result::Result::Ok(value)
+    }
+}
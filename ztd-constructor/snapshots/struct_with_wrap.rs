@@ -0,0 +1,17 @@
+impl<T> Struct<T> {
+    pub fn new(first: impl ::core::convert::Into<T>) -> Self {
+        let first: T = ::core::convert::Into::into(first);
+        let second: String = <String as ::core::default::Default>::default();
+        Self { first, second }
+    }
+    pub fn new_arc(
+        first: impl ::core::convert::Into<T>,
+    ) -> ::ztd_constructor::__private::Arc<Self> {
+        ::ztd_constructor::__private::Arc::new(Self::new(first))
+    }
+    pub fn new_boxed(
+        first: impl ::core::convert::Into<T>,
+    ) -> ::ztd_constructor::__private::Box<Self> {
+        ::ztd_constructor::__private::Box::new(Self::new(first))
+    }
+}
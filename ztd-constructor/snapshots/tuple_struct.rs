@@ -0,0 +1,5 @@
+impl TupleStruct {
+    fn new(value0: String, value1: usize) -> Self {
+        Self(value0, value1)
+    }
+}
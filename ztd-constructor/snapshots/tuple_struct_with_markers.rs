@@ -0,0 +1,11 @@
+impl<T> TupleStruct<T> {
+    pub fn new(value0: T, value5: usize) -> Self {
+        let value1: core::marker::PhantomData<fn() -> T> = core::marker::PhantomData::<
+            fn() -> T,
+        >;
+        let value2: Tag<T> = Tag::<T>;
+        let value3: u8 = 1;
+        let value4: () = ();
+        Self(value0, value1, value2, value3, value4, value5)
+    }
+}
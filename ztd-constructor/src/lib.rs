@@ -1,5 +1,19 @@
 #![no_std]
 
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 pub use ztd_constructor_derive::Constructor;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "alloc")]
+#[doc(hidden)]
+pub mod __private {
+    pub use alloc::boxed::Box;
+    pub use alloc::rc::Rc;
+    #[cfg(target_has_atomic = "ptr")]
+    pub use alloc::sync::Arc;
+}
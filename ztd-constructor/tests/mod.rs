@@ -1,7 +1,9 @@
+use core::marker::PhantomData;
 use quote::quote;
 use trybuild::TestCases;
 use ztd_constructor::Constructor;
 use ztd_constructor_macro::Macro;
+use ztd_test::assert_expansion;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -149,6 +151,7 @@ fn ui() {
     let cases = TestCases::new();
 
     cases.compile_fail("ui/visibility.rs");
+    cases.compile_fail("ui/const.rs");
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -163,3 +166,622 @@ fn default_named() {
 
     let _ = Struct::new();
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct() {
+    assert_expansion(
+        "struct",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            #[Constructor(visibility = pub(crate))]
+            pub struct Struct<'a> {
+                first: &'a str,
+                #[Constructor(default)]
+                second: usize,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_tuple_struct() {
+    assert_expansion(
+        "tuple_struct",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            struct TupleStruct(String, usize);
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn default_value_named() {
+    const DEFAULT_TIMEOUT: u64 = 30;
+
+    #[derive(Constructor)]
+    pub struct Struct {
+        items: Vec<usize>,
+        #[Constructor(default = items.len())]
+        length: usize,
+        #[Constructor(default = DEFAULT_TIMEOUT)]
+        timeout: u64,
+        #[Constructor(default = Vec::with_capacity(length))]
+        buffer: Vec<u8>,
+    }
+
+    let value = Struct::new(vec![1, 2, 3]);
+
+    assert!(value.items == [1, 2, 3]);
+    assert!(value.length == 3);
+    assert!(value.timeout == 30);
+    assert!(value.buffer.capacity() >= 3);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn default_value_unnamed() {
+    #[derive(Constructor)]
+    pub struct TupleStruct(
+        #[Constructor(default)] usize,
+        String,
+        #[Constructor(default = value1.len())] usize,
+    );
+
+    let value = TupleStruct::new(String::from("foo"));
+
+    assert!(value.0 == 0 && value.1 == "foo" && value.2 == 3);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_default_value() {
+    assert_expansion(
+        "struct_with_default_value",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            struct Struct {
+                items: Vec<usize>,
+                #[Constructor(default = items.len())]
+                length: usize,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn into() {
+    #[derive(Constructor)]
+    #[Constructor(into)]
+    struct Config {
+        name: String,
+        path: std::path::PathBuf,
+        #[Constructor(into = false)]
+        retries: Option<u8>,
+    }
+
+    let config = Config::new("foo", "/bar", None);
+
+    assert!(config.name == "foo");
+    assert!(config.path == std::path::Path::new("/bar"));
+    assert!(config.retries.is_none());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn into_field() {
+    #[derive(Constructor)]
+    struct TupleStruct(#[Constructor(into)] String, usize);
+
+    let value = TupleStruct::new('a', 1);
+
+    assert!(value.0 == "a" && value.1 == 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_into() {
+    assert_expansion(
+        "struct_with_into",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            #[Constructor(into)]
+            struct Config {
+                name: String,
+                #[Constructor(into = false)]
+                retries: u8,
+                #[Constructor(default = name.len())]
+                length: usize,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn named_constructors() {
+    #[derive(Constructor)]
+    #[Constructor(name = "empty", fields())]
+    #[Constructor(name = "with_capacity", fields(capacity))]
+    struct Buffer {
+        #[Constructor(default)]
+        data: Vec<u8>,
+        #[Constructor(default = 16)]
+        capacity: usize,
+    }
+
+    assert!(Buffer::new().capacity == 16);
+    assert!(Buffer::empty().data.is_empty());
+    assert!(Buffer::with_capacity(32).capacity == 32);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn named_constructors_replacing_new() {
+    #[derive(Constructor)]
+    #[Constructor(name = "new", fields(1, 0))]
+    #[Constructor(name = "from_second", fields(1))]
+    struct TupleStruct(#[Constructor(default)] usize, String);
+
+    let value = TupleStruct::new(String::from("foo"), 1);
+
+    assert!(value.0 == 1 && value.1 == "foo");
+    assert!(TupleStruct::from_second(String::from("bar")).0 == 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Missing default for field second")]
+fn named_constructor_should_panic_without_default() {
+    Macro::handle(quote!(
+        #[derive(Constructor)]
+        #[Constructor(name = "with_first", fields(first))]
+        struct Struct {
+            first: usize,
+            second: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown field third")]
+fn named_constructor_should_panic_with_unknown_field() {
+    Macro::handle(quote!(
+        #[derive(Constructor)]
+        #[Constructor(name = "with_third", fields(third))]
+        struct Struct {
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_named_constructors() {
+    assert_expansion(
+        "struct_with_named_constructors",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            #[Constructor(name = "empty", fields())]
+            #[Constructor(name = "with_capacity", fields(capacity))]
+            struct Buffer {
+                #[Constructor(default)]
+                data: Vec<u8>,
+                #[Constructor(default = 16)]
+                capacity: usize,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn r#const() {
+    #[derive(Constructor)]
+    #[Constructor(const)]
+    #[Constructor(name = "empty", fields())]
+    struct Entry {
+        #[Constructor(default = "")]
+        name: &'static str,
+        #[Constructor(default = name.len())]
+        length: usize,
+    }
+
+    static ENTRIES: [Entry; 2] = [Entry::new(), Entry::empty()];
+
+    assert!(ENTRIES
+        .iter()
+        .all(|entry| entry.name.is_empty() && entry.length == 0));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn const_with_into() {
+    let output = Macro::handle(quote!(
+        #[derive(Constructor)]
+        #[Constructor(const, into)]
+        struct Struct {
+            first: String,
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("`into` arguments are not supported in `const` constructors"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_const_tuple_struct() {
+    assert_expansion(
+        "const_tuple_struct",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            #[Constructor(const)]
+            pub struct TupleStruct(u8, #[Constructor(default = 42)] usize);
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn validate() {
+    #[derive(Debug, PartialEq)]
+    enum Error {
+        EmptyName,
+        InvalidRange,
+    }
+
+    fn validate_name(name: &str) -> Result<(), Error> {
+        match name.is_empty() {
+            true => Err(Error::EmptyName),
+            false => Ok(()),
+        }
+    }
+
+    fn validate_range(range: &Range) -> Result<(), Error> {
+        match range.start <= range.end {
+            true => Ok(()),
+            false => Err(Error::InvalidRange),
+        }
+    }
+
+    #[derive(Constructor)]
+    #[Constructor(validate = validate_range, error = Error, unchecked)]
+    struct Range {
+        #[Constructor(validate = validate_name)]
+        name: String,
+        start: usize,
+        end: usize,
+    }
+
+    assert!(Range::try_new(String::from("foo"), 1, 2).is_ok());
+    assert!(Range::try_new(String::new(), 1, 2).err() == Some(Error::EmptyName));
+    assert!(Range::try_new(String::from("foo"), 2, 1).err() == Some(Error::InvalidRange));
+    assert!(Range::new(String::new(), 2, 1).name.is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Missing error type for validation")]
+fn validate_should_panic_without_error() {
+    Macro::handle(quote!(
+        #[derive(Constructor)]
+        #[Constructor(validate = validate)]
+        struct Struct {
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(
+    expected = "Named constructor new clashes with the unchecked constructor, rename it"
+)]
+fn unchecked_should_panic_with_named_new() {
+    Macro::handle(quote!(
+        #[derive(Constructor)]
+        #[Constructor(validate = validate, error = Error, unchecked)]
+        #[Constructor(name = "new", fields(first))]
+        struct Struct {
+            first: usize,
+            #[Constructor(default)]
+            second: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_validate() {
+    assert_expansion(
+        "struct_with_validate",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            #[Constructor(validate = Self::validate, error = Error)]
+            struct Range {
+                #[Constructor(validate = validate_name)]
+                name: String,
+                start: usize,
+                end: usize,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn wrap() {
+    #[derive(Constructor)]
+    #[Constructor(wrap = Arc, into)]
+    #[Constructor(name = "with_name", fields(name))]
+    struct Struct {
+        #[Constructor(default)]
+        name: String,
+    }
+
+    let value: std::sync::Arc<Struct> = Struct::new_arc();
+    assert!(value.name.is_empty());
+
+    let value: std::sync::Arc<Struct> = Struct::with_name_arc("foo");
+    assert!(value.name == "foo");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn wrap_multiple() {
+    #[derive(Debug, PartialEq)]
+    struct Error;
+
+    fn validate(value: &Struct) -> Result<(), Error> {
+        match value.0 > 0 {
+            true => Ok(()),
+            false => Err(Error),
+        }
+    }
+
+    #[derive(Constructor)]
+    #[Constructor(wrap(Arc, Rc, Box), validate = validate, error = Error)]
+    struct Struct(usize);
+
+    assert!(Struct::try_new_arc(1).unwrap().0 == 1);
+    assert!(Struct::try_new_rc(1).unwrap().0 == 1);
+    assert!(Struct::try_new_boxed(1).unwrap().0 == 1);
+    assert!(Struct::try_new_boxed(0).err() == Some(Error));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn wrap_enum() {
+    #[derive(Constructor)]
+    #[Constructor(wrap = Box)]
+    enum Enum {
+        First(usize),
+    }
+
+    assert!(matches!(*Enum::first_boxed(1), Enum::First(1)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown wrap, expected `Arc`, `Rc` or `Box`")]
+fn wrap_should_panic_with_unknown_wrap() {
+    Macro::handle(quote!(
+        #[derive(Constructor)]
+        #[Constructor(wrap = Cell)]
+        struct Struct {
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_wrap() {
+    assert_expansion(
+        "struct_with_wrap",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            #[Constructor(wrap(Arc, Box), into)]
+            pub struct Struct<T> {
+                first: T,
+                #[Constructor(default)]
+                second: String,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn marker() {
+    #[derive(Constructor)]
+    struct Struct<T> {
+        first: usize,
+        _second: PhantomData<T>,
+        _third: core::marker::PhantomPinned,
+        _fourth: (),
+    }
+
+    assert!(Struct::<String>::new(1).first == 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn marker_opt_in_and_out() {
+    struct Marker<T>(PhantomData<T>);
+
+    struct Tag;
+
+    #[derive(Constructor)]
+    #[Constructor(const)]
+    struct Struct<T> {
+        #[Constructor(marker = false)]
+        first: PhantomData<T>,
+        #[Constructor(marker)]
+        _second: Tag,
+        #[Constructor(default = Marker(PhantomData))]
+        _third: Marker<T>,
+    }
+
+    const VALUE: Struct<usize> = Struct::new(PhantomData);
+
+    assert!(VALUE.first == PhantomData);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn unit_struct_without_marker() {
+    #[derive(Clone, Copy, PartialEq)]
+    struct Tag;
+
+    #[derive(Constructor)]
+    struct Struct {
+        first: usize,
+        second: Tag,
+    }
+
+    let value = Struct::new(1, Tag);
+
+    assert!(value.first == 1);
+    assert!(value.second == Tag);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct_with_markers_and_defaults() {
+    #[derive(Constructor)]
+    struct TupleStruct<T>(
+        usize,
+        PhantomData<T>,
+        #[Constructor(default = 2)] usize,
+        usize,
+        #[Constructor(default)] usize,
+        usize,
+    );
+
+    let value = TupleStruct::<()>::new(1, 3, 5);
+
+    assert!(value.0 == 1);
+    assert!(value.2 == 2);
+    assert!(value.3 == 3);
+    assert!(value.4 == 0);
+    assert!(value.5 == 5);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_tuple_struct_with_markers() {
+    assert_expansion(
+        "tuple_struct_with_markers",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            pub struct TupleStruct<T>(
+                T,
+                core::marker::PhantomData<fn() -> T>,
+                #[Constructor(marker)] Tag<T>,
+                #[Constructor(default = 1)] u8,
+                (),
+                usize,
+            );
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn r#enum() {
+    #[derive(Constructor)]
+    #[Constructor(into)]
+    enum Shape {
+        Circle {
+            radius: f64,
+        },
+        Rect(f64, f64),
+        #[Constructor(name = "unit")]
+        UnitSquare,
+        HTTPPolygon(#[Constructor(default)] Vec<u8>, String),
+        #[Constructor(skip)]
+        _Skipped,
+    }
+
+    assert!(matches!(Shape::circle(1.0), Shape::Circle { radius } if radius == 1.0));
+    assert!(
+        matches!(Shape::rect(1.0, 2.0), Shape::Rect(width, height) if width == 1.0 && height == 2.0)
+    );
+    assert!(matches!(Shape::unit(), Shape::UnitSquare));
+    assert!(
+        matches!(Shape::http_polygon("foo"), Shape::HTTPPolygon(data, name) if data.is_empty() && name == "foo")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unsupported item")]
+fn union_should_panic() {
+    Macro::handle(quote!(
+        #[derive(Constructor)]
+        union Union {
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_enum() {
+    assert_expansion(
+        "enum",
+        Macro::handle(quote!(
+            #[derive(Constructor)]
+            pub enum Shape<T> {
+                Circle {
+                    radius: T,
+                },
+                Rect(T, T),
+                #[Constructor(name = "empty")]
+                Empty,
+                Type(#[Constructor(default = 1)] u8),
+                #[Constructor(skip)]
+                Skipped,
+            }
+        )),
+    );
+}
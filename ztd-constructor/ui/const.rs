@@ -0,0 +1,15 @@
+use ztd_constructor::Constructor;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Constructor)]
+#[Constructor(const)]
+pub struct Struct {
+    _first: usize,
+    #[Constructor(default)]
+    _second: Vec<usize>,
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn main() {}
@@ -0,0 +1,9 @@
+use proc_macro::TokenStream;
+use ztd_debug_macro::Macro;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[proc_macro_derive(Debug, attributes(Debug))]
+pub fn derive_debug(stream: TokenStream) -> TokenStream {
+    Macro::handle(stream.into()).into()
+}
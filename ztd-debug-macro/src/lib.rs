@@ -0,0 +1,381 @@
+use proc_macro2::TokenStream;
+use quote::{format_ident, quote};
+use std::collections::HashSet;
+use syn::{
+    parse2, parse_quote, Attribute, ExprPath, Field, Fields, Generics, Ident, Item, ItemEnum,
+    ItemStruct, LitInt, WherePredicate,
+};
+use ztd_display_macro::{
+    contains_type_parameter, read_field_bounds, read_options_from_attribute,
+    read_strategy_from_attributes, read_type_parameters, write_crate_path, write_strategy,
+    Strategy,
+};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+enum FieldModifier {
+    Skip,
+    Redact,
+    With(ExprPath),
+    Truncate(LitInt),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn read_field_modifier(field: &Field) -> Option<FieldModifier> {
+    let mut modifier = None;
+
+    for attribute in field
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("Debug"))
+    {
+        let result = attribute.parse_nested_meta(|meta| {
+            if meta.path.is_ident("skip") {
+                modifier = Some(FieldModifier::Skip);
+            } else if meta.path.is_ident("redact") {
+                modifier = Some(FieldModifier::Redact);
+            } else if meta.path.is_ident("with") {
+                modifier = Some(FieldModifier::With(meta.value()?.parse()?));
+            } else if meta.path.is_ident("truncate") {
+                modifier = Some(FieldModifier::Truncate(meta.value()?.parse()?));
+            } else {
+                return Err(meta.error("Unknown attribute"));
+            }
+
+            Ok(())
+        });
+
+        if let Err(error) = result {
+            panic!("{}", error)
+        }
+    }
+
+    modifier
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn read_strategy(attributes: &[Attribute]) -> Option<Strategy> {
+    for attribute in attributes
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("Debug"))
+    {
+        for option in read_options_from_attribute(attribute).into_iter().flatten() {
+            if !option.path.is_ident("with") {
+                panic!("Unknown attribute")
+            }
+        }
+    }
+
+    read_strategy_from_attributes(attributes.iter(), "Debug")
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct FieldData<'a> {
+    field: &'a Field,
+    ident: Ident,
+    modifier: Option<FieldModifier>,
+}
+
+impl<'a> FieldData<'a> {
+    fn read(fields: &'a Fields) -> Vec<Self> {
+        fields
+            .iter()
+            .enumerate()
+            .map(|(index, field)| {
+                let ident = match &field.ident {
+                    Some(ident) => ident.clone(),
+                    None if fields.len() == 1 => format_ident!("value"),
+                    None => format_ident!("value{}", index),
+                };
+
+                Self {
+                    field,
+                    ident,
+                    modifier: read_field_modifier(field),
+                }
+            })
+            .collect()
+    }
+
+    fn is_skipped(&self) -> bool {
+        matches!(self.modifier, Some(FieldModifier::Skip))
+    }
+
+    fn is_bound(&self) -> bool {
+        !matches!(
+            self.modifier,
+            Some(FieldModifier::Skip | FieldModifier::Redact)
+        )
+    }
+
+    fn write_value(&self) -> TokenStream {
+        let ident = &self.ident;
+        let crate_path = write_crate_path("ztd-debug");
+
+        match &self.modifier {
+            Some(FieldModifier::Redact) => quote!(&::core::format_args!("[redacted]")),
+            Some(FieldModifier::With(path)) => quote!(&#crate_path::With::new(#ident, #path)),
+            Some(FieldModifier::Truncate(limit)) => {
+                quote!(&#crate_path::Truncated::new(#ident, #limit))
+            }
+            Some(FieldModifier::Skip) | None => quote!(#ident),
+        }
+    }
+
+    fn write_bound(&self, type_parameters: &HashSet<&Ident>) -> Option<WherePredicate> {
+        let r#type = &self.field.ty;
+
+        if !contains_type_parameter(r#type, type_parameters) {
+            return None;
+        }
+
+        match &self.modifier {
+            Some(FieldModifier::Truncate(_limit)) => {
+                let crate_path = write_crate_path("ztd-debug");
+
+                Some(parse_quote!(#r#type: #crate_path::Truncate))
+            }
+            None => Some(parse_quote!(#r#type: ::core::fmt::Debug)),
+            _ => None,
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_debug_impl<T>(
+    generics: &Generics,
+    name: &Ident,
+    bounds: T,
+    r#impl: TokenStream,
+) -> TokenStream
+where
+    T: Iterator<Item = WherePredicate>,
+{
+    let mut generics = generics.clone();
+    let mut visited = HashSet::new();
+
+    let predicates = bounds
+        .filter(|bound| visited.insert(quote!(#bound).to_string()))
+        .collect::<Vec<_>>();
+
+    if !predicates.is_empty() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::core::fmt::Debug for #name #type_generics #where_clause {
+            #[allow(unused_variables)]
+            fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+                #r#impl
+            }
+        }
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_debug_fields(
+    name: &Ident,
+    fields: &Fields,
+    strategy: &Option<Strategy>,
+    type_parameters: &HashSet<&Ident>,
+) -> (TokenStream, TokenStream, Vec<WherePredicate>) {
+    let field_data = FieldData::read(fields);
+
+    if let Some(inner_strategy) = strategy {
+        let idents = field_data.iter().map(|field_data| &field_data.ident);
+        let pattern = match fields {
+            Fields::Named(_) => quote!({ #(#idents),* }),
+            Fields::Unnamed(_) => quote!((#(#idents),*)),
+            Fields::Unit => quote!(),
+        };
+
+        return (
+            pattern,
+            write_strategy(
+                inner_strategy,
+                field_data.iter().map(|field_data| &field_data.ident),
+            ),
+            read_field_bounds(type_parameters, fields, strategy),
+        );
+    }
+
+    let bindings = field_data.iter().map(|field_data| {
+        let ident = &field_data.ident;
+
+        match (&field_data.field.ident, field_data.is_bound()) {
+            (Some(ident), false) => quote!(#ident: _),
+            (None, false) => quote!(_),
+            _ => quote!(#ident),
+        }
+    });
+
+    let finish = if field_data.iter().any(FieldData::is_skipped) {
+        quote!(.finish_non_exhaustive())
+    } else {
+        quote!(.finish())
+    };
+
+    let entries = field_data
+        .iter()
+        .filter(|field_data| !field_data.is_skipped())
+        .map(|field_data| {
+            let ident = &field_data.ident;
+            let value = field_data.write_value();
+
+            match fields {
+                Fields::Named(_) => quote!(.field(stringify!(#ident), #value)),
+                _ => quote!(.field(#value)),
+            }
+        });
+
+    let (pattern, body) = match fields {
+        Fields::Named(_) => (
+            quote!({ #(#bindings),* }),
+            quote!(formatter.debug_struct(stringify!(#name)) #(#entries)* #finish),
+        ),
+        Fields::Unnamed(_) => (
+            quote!((#(#bindings),*)),
+            quote!(formatter.debug_tuple(stringify!(#name)) #(#entries)* #finish),
+        ),
+        Fields::Unit => (quote!(), quote!(formatter.write_str(stringify!(#name)))),
+    };
+
+    let bounds = field_data
+        .iter()
+        .filter_map(|field_data| field_data.write_bound(type_parameters))
+        .collect();
+
+    (pattern, body, bounds)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct EnumData<'a> {
+    ast: &'a ItemEnum,
+    strategies: Vec<Option<Strategy>>,
+}
+
+impl<'a> EnumData<'a> {
+    fn read(ast: &'a ItemEnum) -> Self {
+        Self {
+            ast,
+            strategies: ast
+                .variants
+                .iter()
+                .map(|variant| read_strategy(&variant.attrs))
+                .collect(),
+        }
+    }
+
+    fn write(self) -> TokenStream {
+        let type_parameters = read_type_parameters(&self.ast.generics);
+        let mut bounds = Vec::new();
+
+        let variants = self
+            .ast
+            .variants
+            .iter()
+            .zip(self.strategies.iter())
+            .map(|(variant, strategy)| {
+                let variant_ident = &variant.ident;
+                let (pattern, body, variant_bounds) =
+                    write_debug_fields(variant_ident, &variant.fields, strategy, &type_parameters);
+
+                bounds.extend(variant_bounds);
+
+                quote!(
+                    Self::#variant_ident #pattern => #body
+                )
+            })
+            .collect::<Vec<_>>();
+
+        write_debug_impl(
+            &self.ast.generics,
+            &self.ast.ident,
+            bounds.into_iter(),
+            quote!(
+                match self {
+                    #(#variants),*
+                }
+            ),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct StructData<'a> {
+    ast: &'a ItemStruct,
+    strategy: Option<Strategy>,
+}
+
+impl<'a> StructData<'a> {
+    fn read(ast: &'a ItemStruct) -> Self {
+        Self {
+            ast,
+            strategy: read_strategy(&ast.attrs),
+        }
+    }
+
+    fn write(self) -> TokenStream {
+        let type_parameters = read_type_parameters(&self.ast.generics);
+        let (pattern, body, bounds) = write_debug_fields(
+            &self.ast.ident,
+            &self.ast.fields,
+            &self.strategy,
+            &type_parameters,
+        );
+
+        write_debug_impl(
+            &self.ast.generics,
+            &self.ast.ident,
+            bounds.into_iter(),
+            quote!(
+                match self {
+                    Self #pattern => #body
+                }
+            ),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+enum Data<'a> {
+    Enum(EnumData<'a>),
+    Struct(StructData<'a>),
+}
+
+impl<'a> Data<'a> {
+    fn read(item: &'a Item) -> Self {
+        match item {
+            Item::Enum(r#enum) => Self::Enum(EnumData::read(r#enum)),
+            Item::Struct(r#struct) => Self::Struct(StructData::read(r#struct)),
+            _ => panic!("Unsupported item"),
+        }
+    }
+
+    fn write(self) -> TokenStream {
+        match self {
+            Self::Enum(r#enum) => r#enum.write(),
+            Self::Struct(r#struct) => r#struct.write(),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct Macro;
+
+impl Macro {
+    pub fn handle(stream: TokenStream) -> TokenStream {
+        Data::read(&parse2::<Item>(stream).unwrap()).write()
+    }
+}
@@ -0,0 +1,14 @@
+impl ::core::fmt::Debug for Enum {
+    #[allow(unused_variables)]
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            Self::First => formatter.write_str(stringify!(First)),
+            Self::Second(value) => {
+                formatter
+                    .debug_tuple(stringify!(Second))
+                    .field(&::ztd_debug::With::new(value, write))
+                    .finish()
+            }
+        }
+    }
+}
@@ -0,0 +1,18 @@
+impl<T> ::core::fmt::Debug for Struct<T>
+where
+    T: ::core::fmt::Debug,
+{
+    #[allow(unused_variables)]
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            Self { first, second: _, third: _, fourth } => {
+                formatter
+                    .debug_struct(stringify!(Struct))
+                    .field(stringify!(first), first)
+                    .field(stringify!(third), &::core::format_args!("[redacted]"))
+                    .field(stringify!(fourth), &::ztd_debug::Truncated::new(fourth, 8))
+                    .finish_non_exhaustive()
+            }
+        }
+    }
+}
@@ -0,0 +1,157 @@
+#![no_std]
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "alloc")]
+extern crate alloc;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[cfg(feature = "alloc")]
+use alloc::boxed::Box;
+#[cfg(feature = "alloc")]
+use alloc::string::String;
+#[cfg(feature = "alloc")]
+use alloc::vec::Vec;
+use core::fmt::{self, Formatter};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub use ztd_debug_derive::Debug;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Debug output limited to a number of characters or bytes, used by `#[Debug(truncate = ...)]`.
+pub trait Truncate {
+    fn fmt_truncated(&self, limit: usize, formatter: &mut Formatter<'_>) -> fmt::Result;
+}
+
+impl Truncate for str {
+    fn fmt_truncated(&self, limit: usize, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self.char_indices().nth(limit) {
+            Some((index, _character)) => {
+                fmt::Debug::fmt(&self[..index], formatter)?;
+                formatter.write_str("...")
+            }
+            None => fmt::Debug::fmt(self, formatter),
+        }
+    }
+}
+
+impl Truncate for [u8] {
+    fn fmt_truncated(&self, limit: usize, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self.get(..limit) {
+            Some(bytes) if bytes.len() < self.len() => formatter
+                .debug_list()
+                .entries(bytes)
+                .finish_non_exhaustive(),
+            _ => fmt::Debug::fmt(self, formatter),
+        }
+    }
+}
+
+impl<const N: usize> Truncate for [u8; N] {
+    fn fmt_truncated(&self, limit: usize, formatter: &mut Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt_truncated(limit, formatter)
+    }
+}
+
+impl<T> Truncate for &T
+where
+    T: Truncate + ?Sized,
+{
+    fn fmt_truncated(&self, limit: usize, formatter: &mut Formatter<'_>) -> fmt::Result {
+        (**self).fmt_truncated(limit, formatter)
+    }
+}
+
+impl<T> Truncate for &mut T
+where
+    T: Truncate + ?Sized,
+{
+    fn fmt_truncated(&self, limit: usize, formatter: &mut Formatter<'_>) -> fmt::Result {
+        (**self).fmt_truncated(limit, formatter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Truncate for String {
+    fn fmt_truncated(&self, limit: usize, formatter: &mut Formatter<'_>) -> fmt::Result {
+        self.as_str().fmt_truncated(limit, formatter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl Truncate for Vec<u8> {
+    fn fmt_truncated(&self, limit: usize, formatter: &mut Formatter<'_>) -> fmt::Result {
+        self.as_slice().fmt_truncated(limit, formatter)
+    }
+}
+
+#[cfg(feature = "alloc")]
+impl<T> Truncate for Box<T>
+where
+    T: Truncate + ?Sized,
+{
+    fn fmt_truncated(&self, limit: usize, formatter: &mut Formatter<'_>) -> fmt::Result {
+        (**self).fmt_truncated(limit, formatter)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub struct Truncated<'a, T>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    limit: usize,
+}
+
+impl<'a, T> Truncated<'a, T>
+where
+    T: ?Sized,
+{
+    pub fn new(value: &'a T, limit: usize) -> Self {
+        Self { value, limit }
+    }
+}
+
+impl<T> fmt::Debug for Truncated<'_, T>
+where
+    T: Truncate + ?Sized,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        self.value.fmt_truncated(self.limit, formatter)
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Debug output delegated to a function, used by `#[Debug(with = ...)]`.
+pub struct With<'a, T, F>
+where
+    T: ?Sized,
+{
+    value: &'a T,
+    function: F,
+}
+
+impl<'a, T, F> With<'a, T, F>
+where
+    T: ?Sized,
+{
+    pub fn new(value: &'a T, function: F) -> Self {
+        Self { value, function }
+    }
+}
+
+impl<T, F> fmt::Debug for With<'_, T, F>
+where
+    T: ?Sized,
+    F: Fn(&T, &mut Formatter<'_>) -> fmt::Result,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        (self.function)(self.value, formatter)
+    }
+}
@@ -0,0 +1,314 @@
+use quote::quote;
+use ztd_debug::Debug;
+use ztd_debug_macro::Macro;
+use ztd_test::assert_expansion;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn r#struct() {
+    #[derive(Debug)]
+    struct Struct {
+        first: String,
+        second: usize,
+    }
+
+    let instance = Struct {
+        first: String::from("foo"),
+        second: 42,
+    };
+
+    assert!(format!("{:?}", instance) == "Struct { first: \"foo\", second: 42 }");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct() {
+    #[derive(Debug)]
+    struct TupleStruct(String, usize);
+
+    assert!(format!("{:?}", TupleStruct(String::from("foo"), 42)) == "TupleStruct(\"foo\", 42)");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn unit_struct() {
+    #[derive(Debug)]
+    struct Struct;
+
+    assert!(format!("{:?}", Struct) == "Struct");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_skip() {
+    #[derive(Debug)]
+    struct Struct {
+        first: usize,
+        #[Debug(skip)]
+        _second: Vec<u8>,
+    }
+
+    let instance = Struct {
+        first: 1,
+        _second: vec![0; 1024],
+    };
+
+    assert!(format!("{:?}", instance) == "Struct { first: 1, .. }");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_redact() {
+    #[derive(Debug)]
+    struct Struct {
+        user: &'static str,
+        #[Debug(redact)]
+        _password: &'static str,
+    }
+
+    let instance = Struct {
+        user: "foo",
+        _password: "bar",
+    };
+
+    assert!(format!("{:?}", instance) == "Struct { user: \"foo\", _password: [redacted] }");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_with() {
+    fn hex(value: &u32, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "{value:#x}")
+    }
+
+    #[derive(Debug)]
+    struct Struct {
+        #[Debug(with = hex)]
+        value: u32,
+    }
+
+    assert!(format!("{:?}", Struct { value: 255 }) == "Struct { value: 0xff }");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_truncate() {
+    #[derive(Debug)]
+    struct Struct {
+        #[Debug(truncate = 3)]
+        text: String,
+        #[Debug(truncate = 2)]
+        bytes: Vec<u8>,
+        #[Debug(truncate = 8)]
+        short: &'static [u8],
+    }
+
+    let instance = Struct {
+        text: String::from("foobar"),
+        bytes: vec![1, 2, 3],
+        short: &[4, 5],
+    };
+
+    assert!(
+        format!("{:?}", instance)
+            == "Struct { text: \"foo\"..., bytes: [1, 2, ..], short: [4, 5] }"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct_with_skip() {
+    #[derive(Debug)]
+    struct TupleStruct(usize, #[Debug(skip)] usize);
+
+    let instance = TupleStruct(1, 2);
+
+    assert!(instance.1 == 2);
+    assert!(format!("{:?}", instance) == "TupleStruct(1, ..)");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn r#enum() {
+    #[derive(Debug)]
+    enum Enum {
+        First,
+        Second {
+            #[Debug(redact)]
+            _secret: String,
+        },
+        Third {
+            #[Debug(truncate = 1)]
+            value: String,
+        },
+    }
+
+    assert!(format!("{:?}", Enum::First) == "First");
+    assert!(
+        format!(
+            "{:?}",
+            Enum::Second {
+                _secret: String::from("foo")
+            }
+        ) == "Second { _secret: [redacted] }"
+    );
+    assert!(
+        format!(
+            "{:?}",
+            Enum::Third {
+                value: String::from("foo")
+            }
+        ) == "Third { value: \"f\"... }"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_message() {
+    #[derive(Debug)]
+    enum Enum {
+        #[Debug("secret({value})")]
+        Secret(usize),
+    }
+
+    assert!(format!("{:?}", Enum::Secret(1)) == "secret(1)");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct() {
+    struct NotDebug;
+
+    #[derive(Debug)]
+    struct Struct<T, U> {
+        first: T,
+        #[Debug(skip)]
+        _second: U,
+    }
+
+    let instance = Struct {
+        first: 1,
+        _second: NotDebug,
+    };
+
+    assert!(format!("{:?}", instance) == "Struct { first: 1, .. }");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown attribute")]
+fn struct_with_unknown_attribute() {
+    Macro::handle(quote!(
+        #[derive(Debug)]
+        struct Struct {
+            #[Debug(foo)]
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown attribute")]
+fn struct_with_unknown_container_attribute() {
+    Macro::handle(quote!(
+        #[derive(Debug)]
+        #[Debug(wth = write)]
+        struct Struct {
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown attribute")]
+fn enum_with_unknown_variant_attribute() {
+    Macro::handle(quote!(
+        #[derive(Debug)]
+        enum Enum {
+            #[Debug(with = write, bound = "T: Debug")]
+            First,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unsupported item")]
+fn r#union() {
+    Macro::handle(quote!(
+        #[derive(Debug)]
+        union Union {
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_formatter() {
+    fn write(value: &Struct, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        write!(formatter, "<{}>", value.first)
+    }
+
+    #[derive(Debug)]
+    #[Debug(with = write)]
+    struct Struct {
+        first: usize,
+    }
+
+    assert!(format!("{:?}", Struct { first: 1 }) == "<1>");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct() {
+    assert_expansion(
+        "struct",
+        Macro::handle(quote!(
+            #[derive(Debug)]
+            struct Struct<T> {
+                first: T,
+                #[Debug(skip)]
+                second: String,
+                #[Debug(redact)]
+                third: String,
+                #[Debug(truncate = 8)]
+                fourth: Vec<u8>,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_enum() {
+    assert_expansion(
+        "enum",
+        Macro::handle(quote!(
+            #[derive(Debug)]
+            enum Enum {
+                First,
+                Second(#[Debug(with = write)] usize),
+            }
+        )),
+    );
+}
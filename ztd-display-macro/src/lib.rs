@@ -3,10 +3,16 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 use proc_macro2::TokenStream;
+use proc_macro_crate::{crate_name, FoundCrate};
 use quote::{format_ident, quote};
+use std::collections::HashSet;
+use syn::punctuated::Punctuated;
+use syn::token::Comma;
+use syn::visit::{self, Visit};
 use syn::{
-    parse2, Attribute, ExprBlock, ExprCall, ExprClosure, ExprPath, Fields, FieldsNamed,
-    FieldsUnnamed, Generics, Ident, Item, ItemEnum, ItemStruct, LitStr, Variant,
+    parse2, parse_quote, Attribute, Expr, ExprBlock, ExprCall, ExprClosure, ExprLit, ExprPath,
+    Field, Fields, FieldsNamed, FieldsUnnamed, Generics, Ident, Item, ItemEnum, ItemStruct, Lit,
+    LitStr, MetaNameValue, Type, TypePath, Variant, WherePredicate,
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -37,31 +43,331 @@ fn read_strategy_from_attribute(attribute: &Attribute) -> Option<Strategy> {
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn read_strategy_from_attributes<'a, T>(mut iterator: T) -> Option<Strategy>
+pub fn read_options_from_attribute(
+    attribute: &Attribute,
+) -> Option<Punctuated<MetaNameValue, Comma>> {
+    attribute
+        .parse_args_with(Punctuated::<MetaNameValue, Comma>::parse_terminated)
+        .ok()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn read_strategy_from_options(options: Punctuated<MetaNameValue, Comma>) -> Option<Strategy> {
+    let option = options
+        .into_iter()
+        .find(|option| option.path.is_ident("with"))?;
+
+    match option.value {
+        Expr::Path(path) => Some(Strategy::With(path)),
+        _ => panic!("Expected path"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn read_strategy_from_attributes<'a, T>(iterator: T, name: &str) -> Option<Strategy>
 where
     T: Iterator<Item = &'a Attribute>,
 {
-    let attribute = match iterator.find(|attribute| attribute.path().is_ident("Display")) {
-        Some(attribute) => attribute,
-        None => return None,
+    for attribute in iterator.filter(|attribute| attribute.path().is_ident(name)) {
+        let strategy = match read_options_from_attribute(attribute) {
+            Some(options) => match read_strategy_from_options(options) {
+                Some(strategy) => strategy,
+                None => continue,
+            },
+            None => match read_strategy_from_attribute(attribute) {
+                Some(strategy) => strategy,
+                None => panic!("Unsupported strategy"),
+            },
+        };
+
+        return Some(strategy);
+    }
+
+    None
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn read_string_option(option: &MetaNameValue) -> &LitStr {
+    match &option.value {
+        Expr::Lit(ExprLit {
+            lit: Lit::Str(value),
+            ..
+        }) => value,
+        _ => panic!("Expected string literal"),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[derive(Default)]
+struct Options {
+    bound: Option<Punctuated<WherePredicate, Comma>>,
+    key: Option<LitStr>,
+}
+
+impl Options {
+    fn read<'a, T>(iterator: T) -> Self
+    where
+        T: Iterator<Item = &'a Attribute>,
+    {
+        let mut options = Self::default();
+
+        for option in iterator
+            .filter(|attribute| attribute.path().is_ident("Display"))
+            .filter_map(read_options_from_attribute)
+            .flatten()
+        {
+            if option.path.is_ident("bound") {
+                let predicates = read_string_option(&option)
+                    .parse_with(Punctuated::<WherePredicate, Comma>::parse_terminated)
+                    .unwrap_or_else(|error| panic!("{}", error));
+
+                options.bound = Some(predicates);
+            } else if option.path.is_ident("key") {
+                options.key = Some(read_string_option(&option).clone());
+            } else if option.path.is_ident("with") {
+                continue;
+            } else {
+                panic!("Unknown attribute")
+            }
+        }
+
+        options
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn read_type_parameters(generics: &Generics) -> HashSet<&Ident> {
+    generics
+        .type_params()
+        .map(|type_parameter| &type_parameter.ident)
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn contains_type_parameter(r#type: &Type, type_parameters: &HashSet<&Ident>) -> bool {
+    struct Visitor<'a> {
+        type_parameters: &'a HashSet<&'a Ident>,
+        found: bool,
+    }
+
+    impl<'a, 'ast> Visit<'ast> for Visitor<'a> {
+        fn visit_type_path(&mut self, path: &'ast TypePath) {
+            if path.qself.is_none() {
+                if let Some(segment) = path.path.segments.first() {
+                    if self.type_parameters.contains(&segment.ident) {
+                        self.found = true;
+                    }
+                }
+            }
+
+            visit::visit_type_path(self, path)
+        }
+    }
+
+    let mut visitor = Visitor {
+        type_parameters,
+        found: false,
     };
 
-    let strategy = read_strategy_from_attribute(attribute);
+    visitor.visit_type(r#type);
+
+    visitor.found
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn read_field_names(fields: &Fields) -> Vec<(String, &Type)> {
+    match fields {
+        Fields::Named(fields) => fields
+            .named
+            .iter()
+            .filter_map(|field| Some((field.ident.as_ref()?.to_string(), &field.ty)))
+            .collect(),
+        Fields::Unnamed(fields) if fields.unnamed.len() == 1 => fields
+            .unnamed
+            .iter()
+            .map(|field| (String::from("value"), &field.ty))
+            .collect(),
+        Fields::Unnamed(fields) => fields
+            .unnamed
+            .iter()
+            .enumerate()
+            .map(|(index, field)| (format!("value{}", index), &field.ty))
+            .collect(),
+        Fields::Unit => Vec::default(),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn read_message_traits(message: &LitStr) -> Vec<(String, TokenStream)> {
+    let message = message.value();
+    let mut characters = message.chars().peekable();
+    let mut traits = Vec::default();
+
+    while let Some(character) = characters.next() {
+        if character != '{' {
+            continue;
+        }
+
+        if characters.peek() == Some(&'{') {
+            characters.next();
+            continue;
+        }
+
+        let argument = characters
+            .by_ref()
+            .take_while(|character| *character != '}')
+            .collect::<String>();
+
+        let (name, specification) = match argument.split_once(':') {
+            Some((name, specification)) => (name.trim(), specification),
+            None => (argument.trim(), ""),
+        };
+
+        let r#trait = match specification.chars().last() {
+            Some('?') => quote!(::core::fmt::Debug),
+            Some('x') => quote!(::core::fmt::LowerHex),
+            Some('X') => quote!(::core::fmt::UpperHex),
+            Some('o') => quote!(::core::fmt::Octal),
+            Some('b') => quote!(::core::fmt::Binary),
+            Some('e') => quote!(::core::fmt::LowerExp),
+            Some('E') => quote!(::core::fmt::UpperExp),
+            Some('p') => quote!(::core::fmt::Pointer),
+            _ => quote!(::core::fmt::Display),
+        };
+
+        traits.push((String::from(name), r#trait));
+    }
+
+    traits
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// `#[Display(argument_skip)]` only leaves a field out of the `Localizable` arguments, the message
+// itself can still refer to it
+fn is_argument_skipped(name: &str, field: &Field) -> bool {
+    field
+        .attrs
+        .iter()
+        .filter(|attribute| attribute.path().is_ident("Display"))
+        .any(|attribute| match attribute.parse_args::<Ident>() {
+            Ok(ident) if ident == "argument_skip" => true,
+            _ => panic!("Unknown attribute on field {}", name),
+        })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn check_argument_skips(fields: &Fields, key: &Option<LitStr>) {
+    for ((name, _type), field) in read_field_names(fields).into_iter().zip(fields.iter()) {
+        if is_argument_skipped(&name, field) && key.is_none() {
+            panic!("Unsupported argument_skip on field {} without key", name)
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn read_argument_names(fields: &Fields) -> Vec<(String, &Type)> {
+    read_field_names(fields)
+        .into_iter()
+        .zip(fields.iter())
+        .filter(|((name, _type), field)| !is_argument_skipped(name, field))
+        .map(|(name, _field)| name)
+        .collect()
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn read_argument_bounds(type_parameters: &HashSet<&Ident>, fields: &Fields) -> Vec<WherePredicate> {
+    read_argument_names(fields)
+        .into_iter()
+        .filter(|(_name, r#type)| contains_type_parameter(r#type, type_parameters))
+        .map(|(_name, r#type)| parse_quote!(#r#type: ::core::fmt::Display))
+        .collect()
+}
 
-    if strategy.is_none() {
-        panic!("Unsupported strategy")
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn read_field_bounds(
+    type_parameters: &HashSet<&Ident>,
+    fields: &Fields,
+    strategy: &Option<Strategy>,
+) -> Vec<WherePredicate> {
+    let fields = read_field_names(fields)
+        .into_iter()
+        .filter(|(_name, r#type)| contains_type_parameter(r#type, type_parameters))
+        .collect::<Vec<_>>();
+
+    match strategy {
+        Some(Strategy::Message(message)) => read_message_traits(message)
+            .into_iter()
+            .filter_map(|(name, r#trait)| {
+                let (_name, r#type) = fields.iter().find(|(field, _type)| *field == name)?;
+
+                Some(parse_quote!(#r#type: #r#trait))
+            })
+            .collect(),
+        None => fields
+            .iter()
+            .map(|(_name, r#type)| parse_quote!(#r#type: ::core::fmt::Debug))
+            .collect(),
+        _ => Vec::default(),
     }
+}
 
-    strategy
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_generics<T>(generics: &Generics, options: &Options, bounds: T) -> Generics
+where
+    T: Iterator<Item = WherePredicate>,
+{
+    let mut generics = generics.clone();
+
+    let predicates = match &options.bound {
+        Some(bound) => bound.iter().cloned().collect::<Vec<_>>(),
+        None => {
+            let mut visited = HashSet::new();
+
+            bounds
+                .filter(|bound| visited.insert(quote!(#bound).to_string()))
+                .collect()
+        }
+    };
+
+    if !predicates.is_empty() {
+        generics.make_where_clause().predicates.extend(predicates);
+    }
+
+    generics
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn write_display_impl(generics: &Generics, name: &Ident, r#impl: TokenStream) -> TokenStream {
+fn write_display_impl<T>(
+    generics: &Generics,
+    name: &Ident,
+    options: &Options,
+    bounds: T,
+    r#impl: TokenStream,
+) -> TokenStream
+where
+    T: Iterator<Item = WherePredicate>,
+{
+    let generics = write_generics(generics, options, bounds);
     let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
 
     quote!(
         impl #impl_generics ::core::fmt::Display for #name #type_generics #where_clause {
+            #[allow(unused_variables)]
             fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
                 #r#impl
             }
@@ -71,6 +377,106 @@ fn write_display_impl(generics: &Generics, name: &Ident, r#impl: TokenStream) ->
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+// The runtime items are either reached through their own crate or through `ztd`
+pub fn write_crate_path(package: &str) -> TokenStream {
+    let name = match crate_name(package).or_else(|_error| crate_name("ztd")) {
+        Ok(FoundCrate::Name(name)) => name,
+        Ok(FoundCrate::Itself) | Err(_) => package.replace('-', "_"),
+    };
+
+    let name = format_ident!("{}", name);
+
+    quote!(::#name)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_localizable_impl<T>(
+    generics: &Generics,
+    name: &Ident,
+    options: &Options,
+    bounds: T,
+    key_impl: TokenStream,
+    arguments_impl: TokenStream,
+) -> TokenStream
+where
+    T: Iterator<Item = WherePredicate>,
+{
+    let generics = write_generics(generics, options, bounds);
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+    let crate_path = write_crate_path("ztd-display");
+
+    quote!(
+        impl #impl_generics #crate_path::Localizable for #name #type_generics #where_clause {
+            fn message_key(&self) -> &'static str {
+                #key_impl
+            }
+
+            fn message_arguments(
+                &self,
+                visitor: &mut dyn FnMut(&'static str, &dyn ::core::fmt::Display),
+            ) {
+                #arguments_impl
+            }
+        }
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_localizable_arguments(fields: &Fields) -> (TokenStream, TokenStream) {
+    let bindings =
+        read_field_names(fields)
+            .into_iter()
+            .zip(fields.iter())
+            .map(|((name, _type), field)| {
+                let ident = format_ident!("{}", name);
+
+                match (is_argument_skipped(&name, field), fields) {
+                    (true, Fields::Named(_)) => quote!(#ident: _),
+                    (true, _) => quote!(_),
+                    (false, _) => quote!(#ident),
+                }
+            });
+
+    let pattern = match fields {
+        Fields::Named(_) => quote!({ #(#bindings),* }),
+        Fields::Unnamed(_) => quote!((#(#bindings),*)),
+        Fields::Unit => quote!(),
+    };
+
+    let names = read_argument_names(fields)
+        .into_iter()
+        .map(|(name, _type)| (format_ident!("{}", name), name))
+        .collect::<Vec<_>>();
+
+    let visits = names.iter().map(|(ident, name)| {
+        quote!(
+            visitor(#name, #ident);
+        )
+    });
+
+    (pattern, quote!(#(#visits)*))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn write_strategy<'a, T>(strategy: &Strategy, idents: T) -> TokenStream
+where
+    T: Iterator<Item = &'a Ident>,
+{
+    match strategy {
+        Strategy::Message(message) => quote!(write!(formatter, #message)),
+        Strategy::Closure(closure) => quote!(write!(formatter, "{}", (#closure)())),
+        Strategy::Block(block) => quote!(write!(formatter, "{}", #block)),
+        Strategy::Call(call) => quote!(write!(formatter, "{}", #call)),
+        Strategy::Path(path) => quote!(write!(formatter, "{}", #path(#(#idents),*))),
+        Strategy::With(path) => quote!(#path(self, formatter)),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 fn write_display_named_fields<T>(
     name: &Ident,
     fields: &FieldsNamed,
@@ -81,15 +487,10 @@ where
     T: FnOnce(TokenStream) -> TokenStream,
 {
     match strategy {
-        Some(Strategy::Message(message)) => r#impl(quote!(write!(formatter, #message))),
-        Some(Strategy::Closure(closure)) => r#impl(quote!(write!(formatter, "{}", (#closure)()))),
-        Some(Strategy::Block(block)) => r#impl(quote!(write!(formatter, "{}", #block))),
-        Some(Strategy::Call(call)) => r#impl(quote!(write!(formatter, "{}", #call))),
-        Some(Strategy::Path(path)) => {
-            let idents = fields.named.iter().map(|field| &field.ident);
-
-            r#impl(quote!(write!(formatter, "{}", #path(#(#idents),*))))
-        }
+        Some(strategy) => r#impl(write_strategy(
+            strategy,
+            fields.named.iter().flat_map(|field| &field.ident),
+        )),
         None => {
             let assignments = fields.named.iter().map(|field| {
                 let ident = &field.ident;
@@ -120,36 +521,27 @@ fn write_display_unnamed_fields<T>(
 where
     T: FnOnce(TokenStream) -> TokenStream,
 {
-    let field_idents = if fields.unnamed.len() == 1 {
-        let ident = format_ident!("value");
-
-        quote!(#ident)
+    let idents = if fields.unnamed.len() == 1 {
+        vec![format_ident!("value")]
     } else {
-        let fields = fields
+        fields
             .unnamed
             .iter()
             .enumerate()
-            .map(|(index, _field)| format_ident!("value{}", index));
-
-        quote!(#(#fields),*)
+            .map(|(index, _field)| format_ident!("value{}", index))
+            .collect()
     };
 
+    let field_idents = quote!(#(#idents),*);
+
     match strategy {
-        Some(Strategy::Message(message)) => r#impl(quote!(
-            (#field_idents) => write!(formatter, #message)
-        )),
-        Some(Strategy::Closure(closure)) => r#impl(quote!(
-            (#field_idents) => write!(formatter, "{}", (#closure)())
-        )),
-        Some(Strategy::Block(block)) => r#impl(quote!(
-            (#field_idents) => write!(formatter, "{}", #block)
-        )),
-        Some(Strategy::Call(call)) => r#impl(quote!(
-            (#field_idents) => write!(formatter, "{}", #call)
-        )),
-        Some(Strategy::Path(path)) => r#impl(quote!(
-            (#field_idents) => write!(formatter, "{}", #path(#field_idents))
-        )),
+        Some(strategy) => {
+            let strategy_impl = write_strategy(strategy, idents.iter());
+
+            r#impl(quote!(
+                (#field_idents) => #strategy_impl
+            ))
+        }
         None => {
             let assignments = if fields.unnamed.len() == 1 {
                 let ident = format_ident!("value");
@@ -180,36 +572,26 @@ where
 
 fn write_display_unit_fields(name: &Ident, strategy: &Option<Strategy>) -> TokenStream {
     match strategy {
-        Some(Strategy::Message(message)) => quote!(write!(formatter, #message)),
-        Some(Strategy::Closure(closure)) => {
-            quote!(write!(formatter, "{}", (#closure)()))
-        }
-        Some(Strategy::Block(block)) => {
-            quote!(write!(formatter, "{}", #block))
-        }
-        Some(Strategy::Call(call)) => {
-            quote!(write!(formatter, "{}", #call))
-        }
-        Some(Strategy::Path(path)) => {
-            quote!(write!(formatter, "{}", #path()))
-        }
+        Some(strategy) => write_strategy(strategy, [].iter()),
         None => quote!(formatter.debug_struct(stringify!(#name)).finish()),
     }
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-enum Strategy {
+pub enum Strategy {
     Message(LitStr),
     Closure(ExprClosure),
     Block(ExprBlock),
     Call(ExprCall),
     Path(ExprPath),
+    With(ExprPath),
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 struct EnumVariantData {
+    options: Options,
     strategy: Option<Strategy>,
 }
 
@@ -217,6 +599,7 @@ struct EnumVariantData {
 
 struct EnumData<'a> {
     ast: &'a ItemEnum,
+    options: Options,
     variants: Vec<EnumVariantData>,
 }
 
@@ -224,12 +607,25 @@ impl<'a> EnumData<'a> {
     fn read(ast: &'a ItemEnum) -> Self {
         let mut data = Self {
             ast,
+            options: Options::read(ast.attrs.iter()),
             variants: Vec::with_capacity(ast.variants.len()),
         };
 
+        if data.options.key.is_some() {
+            panic!("Unsupported key for enums, add a key to every variant instead")
+        }
+
         for variant in &data.ast.variants {
+            let options = Options::read(variant.attrs.iter());
+            check_argument_skips(&variant.fields, &options.key);
+
+            if options.bound.is_some() {
+                panic!("Unsupported bound for variants, add the bound to the enum instead")
+            }
+
             data.variants.push(EnumVariantData {
-                strategy: read_strategy_from_attributes(variant.attrs.iter()),
+                options,
+                strategy: read_strategy_from_attributes(variant.attrs.iter(), "Display"),
             });
         }
 
@@ -244,17 +640,89 @@ impl<'a> EnumData<'a> {
             .enumerate()
             .map(|(variant_index, variant)| self.write_variant(variant, variant_index));
 
-        write_display_impl(
+        let type_parameters = read_type_parameters(&self.ast.generics);
+        let bounds = self.ast.variants.iter().zip(self.variants.iter()).flat_map(
+            |(variant, variant_data)| {
+                read_field_bounds(&type_parameters, &variant.fields, &variant_data.strategy)
+            },
+        );
+
+        let display_impl = write_display_impl(
             &self.ast.generics,
             &self.ast.ident,
+            &self.options,
+            bounds,
             quote!(
                 match self {
                     #(#variants),*
                 }
             ),
+        );
+
+        let localizable_impl = self.write_localizable(&type_parameters);
+
+        quote!(
+            #display_impl
+
+            #localizable_impl
         )
     }
 
+    fn write_localizable(&self, type_parameters: &HashSet<&Ident>) -> Option<TokenStream> {
+        if self
+            .variants
+            .iter()
+            .all(|variant_data| variant_data.options.key.is_none())
+        {
+            return None;
+        }
+
+        let mut keys = Vec::with_capacity(self.variants.len());
+        let mut arguments = Vec::with_capacity(self.variants.len());
+
+        for (variant, variant_data) in self.ast.variants.iter().zip(self.variants.iter()) {
+            let key = match &variant_data.options.key {
+                Some(key) => key,
+                None => panic!("Missing key for variant {}", variant.ident),
+            };
+
+            let variant_ident = &variant.ident;
+            let (pattern, visits) = write_localizable_arguments(&variant.fields);
+
+            keys.push(quote!(
+                Self::#variant_ident { .. } => #key
+            ));
+            arguments.push(quote!(
+                Self::#variant_ident #pattern => {
+                    #visits
+                }
+            ));
+        }
+
+        let bounds = self
+            .ast
+            .variants
+            .iter()
+            .flat_map(|variant| read_argument_bounds(type_parameters, &variant.fields));
+
+        Some(write_localizable_impl(
+            &self.ast.generics,
+            &self.ast.ident,
+            &self.options,
+            bounds,
+            quote!(
+                match self {
+                    #(#keys),*
+                }
+            ),
+            quote!(
+                match self {
+                    #(#arguments),*
+                }
+            ),
+        ))
+    }
+
     fn write_variant(&self, variant: &Variant, variant_index: usize) -> TokenStream {
         #[coverage(off)]
         fn get_variant_data<'a>(
@@ -311,14 +779,19 @@ impl<'a> EnumData<'a> {
 
 struct StructData<'a> {
     ast: &'a ItemStruct,
+    options: Options,
     strategy: Option<Strategy>,
 }
 
 impl<'a> StructData<'a> {
     fn read(ast: &'a ItemStruct) -> Self {
+        let options = Options::read(ast.attrs.iter());
+        check_argument_skips(&ast.fields, &options.key);
+
         Self {
             ast,
-            strategy: read_strategy_from_attributes(ast.attrs.iter()),
+            options,
+            strategy: read_strategy_from_attributes(ast.attrs.iter(), "Display"),
         }
     }
 
@@ -347,7 +820,45 @@ impl<'a> StructData<'a> {
             Fields::Unit => write_display_unit_fields(&self.ast.ident, &self.strategy),
         };
 
-        write_display_impl(&self.ast.generics, &self.ast.ident, r#impl)
+        let type_parameters = read_type_parameters(&self.ast.generics);
+        let bounds = read_field_bounds(&type_parameters, &self.ast.fields, &self.strategy);
+
+        let display_impl = write_display_impl(
+            &self.ast.generics,
+            &self.ast.ident,
+            &self.options,
+            bounds.into_iter(),
+            r#impl,
+        );
+
+        let localizable_impl = self.write_localizable(&type_parameters);
+
+        quote!(
+            #display_impl
+
+            #localizable_impl
+        )
+    }
+
+    fn write_localizable(&self, type_parameters: &HashSet<&Ident>) -> Option<TokenStream> {
+        let key = self.options.key.as_ref()?;
+        let (pattern, visits) = write_localizable_arguments(&self.ast.fields);
+        let bounds = read_argument_bounds(type_parameters, &self.ast.fields);
+
+        Some(write_localizable_impl(
+            &self.ast.generics,
+            &self.ast.ident,
+            &self.options,
+            bounds.into_iter(),
+            quote!(#key),
+            quote!(
+                match self {
+                    Self #pattern => {
+                        #visits
+                    }
+                }
+            ),
+        ))
     }
 }
 
@@ -0,0 +1,40 @@
+impl ::core::fmt::Display for Enum {
+    #[allow(unused_variables)]
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            Self::First => write!(formatter, "first"),
+            Self::Second(value) => {
+                formatter.debug_tuple(stringify!(Second)).field(value).finish()
+            }
+            Self::Third { value } => {
+                formatter
+                    .debug_struct(stringify!(Third))
+                    .field(stringify!(value), &value)
+                    .finish()
+            }
+        }
+    }
+}
+impl ::ztd_display::Localizable for Enum {
+    fn message_key(&self) -> &'static str {
+        match self {
+            Self::First { .. } => "errors.first",
+            Self::Second { .. } => "errors.second",
+            Self::Third { .. } => "errors.third",
+        }
+    }
+    fn message_arguments(
+        &self,
+        visitor: &mut dyn FnMut(&'static str, &dyn ::core::fmt::Display),
+    ) {
+        match self {
+            Self::First => {}
+            Self::Second(value) => {
+                visitor("value", value);
+            }
+            Self::Third { value } => {
+                visitor("value", value);
+            }
+        }
+    }
+}
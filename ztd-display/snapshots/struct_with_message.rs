@@ -0,0 +1,11 @@
+impl<T> ::core::fmt::Display for Struct<T>
+where
+    T: ::core::fmt::Display,
+{
+    #[allow(unused_variables)]
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter) -> ::core::fmt::Result {
+        match self {
+            Self { first, second } => write!(formatter, "{first} and then {second}"),
+        }
+    }
+}
@@ -2,4 +2,61 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+use core::fmt::{self, Display, Formatter};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
 pub use ztd_display_derive::Display;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Message key and arguments of a value, implemented by `#[Display(key = "...")]`.
+pub trait Localizable {
+    fn message_key(&self) -> &'static str;
+
+    fn message_arguments(&self, visitor: &mut dyn FnMut(&'static str, &dyn Display));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Renders [`Localizable`] values, returning `None` if the key is unknown.
+pub trait Localizer {
+    fn localize(
+        &self,
+        value: &dyn Localizable,
+        formatter: &mut Formatter<'_>,
+    ) -> Option<fmt::Result>;
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+/// Displays a value with a [`Localizer`], falling back to its own [`Display`] implementation.
+pub struct Localized<'a, T, L>
+where
+    L: ?Sized,
+{
+    value: &'a T,
+    localizer: &'a L,
+}
+
+impl<'a, T, L> Localized<'a, T, L>
+where
+    L: ?Sized,
+{
+    pub fn new(value: &'a T, localizer: &'a L) -> Self {
+        Self { value, localizer }
+    }
+}
+
+impl<T, L> Display for Localized<'_, T, L>
+where
+    T: Localizable + Display,
+    L: Localizer + ?Sized,
+{
+    fn fmt(&self, formatter: &mut Formatter<'_>) -> fmt::Result {
+        match self.localizer.localize(self.value, formatter) {
+            Some(result) => result,
+            None => Display::fmt(self.value, formatter),
+        }
+    }
+}
@@ -1,6 +1,7 @@
 use quote::quote;
-use ztd_display::Display;
+use ztd_display::{Display, Localizable, Localized, Localizer};
 use ztd_display_macro::Macro;
+use ztd_test::assert_expansion;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -531,3 +532,424 @@ fn r#union() {
         }
     ));
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct() {
+    #[derive(Debug, Display)]
+    struct Struct<T> {
+        inner: T,
+    }
+
+    let instance = Struct { inner: 42 };
+
+    assert!(format!("{:?}", instance) == format!("{}", instance));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct_with_message() {
+    #[derive(Display)]
+    #[Display("inner is {inner}")]
+    struct Struct<T> {
+        inner: T,
+    }
+
+    assert!(format!("{}", Struct { inner: 42 }) == "inner is 42");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct_with_debug_message() {
+    #[derive(Debug)]
+    struct Inner;
+
+    #[derive(Display)]
+    #[Display("inner is {inner:?}")]
+    struct Struct<T> {
+        inner: T,
+    }
+
+    assert!(format!("{}", Struct { inner: Inner }) == "inner is Inner");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_tuple_struct_with_hex_message() {
+    #[derive(Display)]
+    #[Display("{value:#x}")]
+    struct TupleStruct<T>(T);
+
+    assert!(format!("{}", TupleStruct(255u8)) == "0xff");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_enum() {
+    #[derive(Display)]
+    enum Enum<T, U> {
+        #[Display("first {value}")]
+        First(T),
+        Second(Vec<U>),
+    }
+
+    assert!(format!("{}", Enum::<usize, usize>::First(1)) == "first 1");
+    assert!(format!("{}", Enum::<usize, usize>::Second(vec![2])) == "Second([2])");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct_with_bound() {
+    fn foobar<T>(inner: &T) -> String
+    where
+        T: ToString,
+    {
+        inner.to_string()
+    }
+
+    #[derive(Display)]
+    #[Display(foobar)]
+    #[Display(bound = "T: ToString")]
+    struct Struct<T> {
+        inner: T,
+    }
+
+    assert!(format!("{}", Struct { inner: 42 }) == "42");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown attribute")]
+fn struct_with_unknown_option() {
+    Macro::handle(quote!(
+        #[derive(Display)]
+        #[Display(foo = "bar")]
+        struct Struct;
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Catalog;
+
+impl Localizer for Catalog {
+    fn localize(
+        &self,
+        value: &dyn Localizable,
+        formatter: &mut std::fmt::Formatter<'_>,
+    ) -> Option<std::fmt::Result> {
+        let mut arguments = Vec::new();
+
+        value.message_arguments(&mut |name, argument| {
+            arguments.push(format!("{name}={argument}"));
+        });
+
+        match value.message_key() {
+            "errors.unknown" => None,
+            key => Some(write!(formatter, "{key}({})", arguments.join(","))),
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_key() {
+    #[derive(Display)]
+    #[Display("{path} not found")]
+    #[Display(key = "errors.not_found")]
+    struct Struct {
+        path: String,
+    }
+
+    let instance = Struct {
+        path: String::from("foo"),
+    };
+
+    assert!(instance.message_key() == "errors.not_found");
+    assert!(format!("{}", Localized::new(&instance, &Catalog)) == "errors.not_found(path=foo)");
+    assert!(format!("{}", instance) == "foo not found");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_keys() {
+    #[derive(Display)]
+    enum Enum<T> {
+        #[Display("{value0} of {value1}")]
+        #[Display(key = "errors.range")]
+        Range(T, usize),
+        #[Display("unknown")]
+        #[Display(key = "errors.unknown")]
+        Unknown,
+    }
+
+    assert!(
+        format!("{}", Localized::new(&Enum::Range(1, 2), &Catalog))
+            == "errors.range(value0=1,value1=2)"
+    );
+    assert!(format!("{}", Localized::new(&Enum::<usize>::Unknown, &Catalog)) == "unknown");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_key_and_skipped_argument() {
+    #[derive(Display)]
+    #[Display("{path} not found")]
+    #[Display(key = "errors.not_found")]
+    struct Struct {
+        path: String,
+        #[Display(argument_skip)]
+        _candidates: Vec<String>,
+    }
+
+    let instance = Struct {
+        path: String::from("foo"),
+        _candidates: vec![String::from("bar")],
+    };
+
+    assert!(format!("{}", Localized::new(&instance, &Catalog)) == "errors.not_found(path=foo)");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_keys_and_skipped_argument() {
+    #[derive(Display)]
+    enum Enum<T> {
+        #[Display("{value1}")]
+        #[Display(key = "errors.range")]
+        Range(#[Display(argument_skip)] T, usize),
+    }
+
+    struct Opaque;
+
+    assert!(
+        format!("{}", Localized::new(&Enum::Range(Opaque, 2), &Catalog))
+            == "errors.range(value1=2)"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown attribute on field first")]
+fn struct_with_unknown_field_attribute() {
+    Macro::handle(quote!(
+        #[derive(Display)]
+        #[Display(key = "key")]
+        struct Struct {
+            #[Display(hide)]
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown attribute on field first")]
+fn struct_without_key_with_unknown_field_attribute() {
+    Macro::handle(quote!(
+        #[derive(Display)]
+        struct Struct {
+            #[Display(hide)]
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unsupported argument_skip on field value1 without key")]
+fn enum_variant_without_key_with_skipped_argument() {
+    Macro::handle(quote!(
+        #[derive(Display)]
+        enum Enum {
+            #[Display("{value0}")]
+            Range(usize, #[Display(argument_skip)] usize),
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unsupported bound for variants, add the bound to the enum instead")]
+fn enum_variant_with_bound() {
+    Macro::handle(quote!(
+        #[derive(Display)]
+        enum Enum<T> {
+            #[Display("{value}")]
+            #[Display(bound = "T: Display")]
+            Value(T),
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unsupported key for enums, add a key to every variant instead")]
+fn enum_with_container_key() {
+    Macro::handle(quote!(
+        #[derive(Display)]
+        #[Display(key = "errors")]
+        enum Enum {
+            First,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Missing key for variant Second")]
+fn enum_with_missing_key() {
+    Macro::handle(quote!(
+        #[derive(Display)]
+        enum Enum {
+            #[Display(key = "first")]
+            First,
+            Second,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_selected_arguments() {
+    fn foobar(first: &str, third: &usize) -> String {
+        format!("{first} and {third}")
+    }
+
+    #[derive(Display)]
+    #[Display(foobar(first, third))]
+    struct Struct {
+        first: String,
+        second: bool,
+        third: usize,
+    }
+
+    let instance = Struct {
+        first: String::from("foo"),
+        second: true,
+        third: 42,
+    };
+
+    assert!(instance.second);
+    assert!(format!("{}", instance) == "foo and 42");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct_with_selected_arguments() {
+    fn foobar(second: &usize) -> String {
+        format!("{second}")
+    }
+
+    #[derive(Display)]
+    #[Display(foobar(value1))]
+    struct TupleStruct(bool, usize);
+
+    let instance = TupleStruct(true, 42);
+
+    assert!(instance.0);
+    assert!(format!("{}", instance) == "42");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_formatter() {
+    #[derive(Display)]
+    #[Display(with = Struct::write)]
+    struct Struct {
+        first: String,
+    }
+
+    impl Struct {
+        fn write(&self, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+            write!(formatter, "Hello {}", self.first)
+        }
+    }
+
+    let instance = Struct {
+        first: String::from("foo"),
+    };
+
+    assert!(format!("{}", instance) == "Hello foo");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_formatter() {
+    fn write(value: &Enum, formatter: &mut std::fmt::Formatter) -> std::fmt::Result {
+        match value {
+            Enum::First(value) => write!(formatter, "first {value}"),
+            Enum::Second => write!(formatter, "second"),
+        }
+    }
+
+    #[derive(Display)]
+    enum Enum {
+        #[Display(with = write)]
+        First(usize),
+        #[Display(with = write)]
+        Second,
+    }
+
+    assert!(format!("{}", Enum::First(1)) == "first 1");
+    assert!(format!("{}", Enum::Second) == "second");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_message() {
+    assert_expansion(
+        "struct_with_message",
+        Macro::handle(quote!(
+            #[derive(Display)]
+            #[Display("{first} and then {second}")]
+            struct Struct<T> {
+                first: T,
+                second: String,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_enum() {
+    assert_expansion(
+        "enum",
+        Macro::handle(quote!(
+            #[derive(Display)]
+            enum Enum {
+                #[Display("first")]
+                #[Display(key = "errors.first")]
+                First,
+                #[Display(key = "errors.second")]
+                Second(String),
+                #[Display(key = "errors.third")]
+                Third { value: usize },
+            }
+        )),
+    );
+}
@@ -0,0 +1 @@
+impl<T> ::core::error::Error for Enum<T> {}
@@ -1,6 +1,7 @@
 use quote::quote;
 use ztd_error::Error;
 use ztd_error_macro::Macro;
+use ztd_test::assert_expansion;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -59,3 +60,18 @@ fn r#union() {
         }
     ));
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_enum() {
+    assert_expansion(
+        "enum",
+        Macro::handle(quote!(
+            #[derive(Error)]
+            enum Enum<T> {
+                Case(T),
+            }
+        )),
+    );
+}
@@ -3,10 +3,14 @@
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
 use proc_macro2::TokenStream;
-use quote::{format_ident, quote};
+use quote::{format_ident, quote, quote_spanned};
+use syn::meta::ParseNestedMeta;
+use syn::spanned::Spanned;
+use syn::token::Paren;
 use syn::{
-    parse2, Fields, FieldsNamed, FieldsUnnamed, Generics, Ident, Index, Item, ItemEnum, ItemStruct,
-    Meta, Variant,
+    parenthesized, parse2, parse_quote, Error, Expr, ExprPath, Field, Fields, GenericArgument,
+    GenericParam, Generics, Ident, Index, Item, ItemEnum, ItemStruct, LitStr, Meta, PathArguments,
+    Result, Type, Variant, WherePredicate,
 };
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -28,86 +32,435 @@ fn write_from_impl(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn write_from_named_fields_impl(
+#[derive(Default)]
+struct Options {
+    into: bool,
+    reverse: bool,
+    via: Option<Type>,
+    r#ref: bool,
+    boxed: bool,
+}
+
+impl Options {
+    fn read(&mut self, meta: &ParseNestedMeta) -> Result<bool> {
+        if meta.path.is_ident("into") {
+            self.into = true;
+        } else if meta.path.is_ident("reverse") {
+            self.reverse = true;
+        } else if meta.path.is_ident("via") {
+            self.via = Some(meta.value()?.parse()?);
+        } else if meta.path.is_ident("ref") {
+            self.r#ref = true;
+        } else if meta.path.is_ident("boxed") {
+            self.boxed = true;
+        } else {
+            return Ok(false);
+        }
+
+        Ok(true)
+    }
+
+    fn or(&self, other: &Self) -> Self {
+        Self {
+            into: self.into || other.into,
+            reverse: self.reverse || other.reverse,
+            via: self.via.clone().or_else(|| other.via.clone()),
+            r#ref: self.r#ref || other.r#ref,
+            boxed: self.boxed || other.boxed,
+        }
+    }
+
+    fn is_generic(&self) -> bool {
+        self.into || self.via.is_some()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn read_boxed(r#type: &Type) -> Option<&Type> {
+    let segment = match r#type {
+        Type::Path(path) if path.qself.is_none() => path.path.segments.last()?,
+        _ => return None,
+    };
+
+    if segment.ident != "Box" {
+        return None;
+    }
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) if arguments.args.len() == 1 => {
+            match arguments.args.first() {
+                Some(GenericArgument::Type(r#type)) => Some(r#type),
+                _ => None,
+            }
+        }
+        _ => None,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn is_reflexive(r#type: &Type, generics: &Generics) -> bool {
+    let path = match r#type {
+        Type::Reference(reference) => return is_reflexive(&reference.elem, generics),
+        Type::Paren(paren) => return is_reflexive(&paren.elem, generics),
+        Type::Group(group) => return is_reflexive(&group.elem, generics),
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return false,
+    };
+
+    if let Some(ident) = path.get_ident() {
+        return generics
+            .type_params()
+            .any(|type_parameter| type_parameter.ident == *ident);
+    }
+
+    let segment = match path.segments.last() {
+        Some(segment) if segment.ident == "Box" || segment.ident == "Pin" => segment,
+        _ => return false,
+    };
+
+    match &segment.arguments {
+        PathArguments::AngleBracketed(arguments) => match arguments.args.first() {
+            Some(GenericArgument::Type(r#type)) => is_reflexive(r#type, generics),
+            _ => false,
+        },
+        _ => false,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+// `impl<S> From<S> for T where Via: From<S>` only coexists with other `From` impls if `Via` is local,
+// paths into other crates cannot be told apart from local ones, so only obvious cases are caught
+fn is_foreign(r#type: &Type) -> bool {
+    const FOREIGN: &[&str] = &[
+        "bool", "char", "str", "f32", "f64", "i8", "i16", "i32", "i64", "i128", "isize", "u8",
+        "u16", "u32", "u64", "u128", "usize", "String", "Vec", "Option", "Result",
+    ];
+
+    let path = match r#type {
+        Type::Reference(reference) => return is_foreign(&reference.elem),
+        Type::Paren(paren) => return is_foreign(&paren.elem),
+        Type::Group(group) => return is_foreign(&group.elem),
+        Type::Path(path) if path.qself.is_none() => &path.path,
+        _ => return true,
+    };
+
+    if let Some(inner) = read_boxed(r#type) {
+        return is_foreign(inner);
+    }
+
+    let first = match path.segments.first() {
+        Some(first) => first,
+        None => return true,
+    };
+
+    path.leading_colon.is_some()
+        || ["std", "core", "alloc"]
+            .iter()
+            .any(|name| first.ident == name)
+        || (path.segments.len() == 1 && FOREIGN.iter().any(|name| first.ident == name))
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+enum FieldDataModifier {
+    Default,
+    Value(Expr),
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct FieldData<'a> {
+    field: &'a Field,
+    modifier: Option<FieldDataModifier>,
+    rename: Option<Ident>,
+    with: Option<ExprPath>,
+}
+
+impl<'a> FieldData<'a> {
+    fn read(fields: &'a Fields) -> Vec<Self> {
+        fields
+            .iter()
+            .map(|field| {
+                let mut data = Self {
+                    field,
+                    modifier: None,
+                    rename: None,
+                    with: None,
+                };
+
+                for attribute in field
+                    .attrs
+                    .iter()
+                    .filter(|attribute| attribute.path().is_ident("From"))
+                {
+                    attribute
+                        .parse_nested_meta(|meta| {
+                            if meta.path.is_ident("default") {
+                                data.modifier = Some(FieldDataModifier::Default);
+                            } else if meta.path.is_ident("value") {
+                                data.modifier =
+                                    Some(FieldDataModifier::Value(meta.value()?.parse()?));
+                            } else if meta.path.is_ident("rename") {
+                                data.rename = Some(meta.value()?.parse::<LitStr>()?.parse()?);
+                            } else if meta.path.is_ident("with") {
+                                data.with = Some(meta.value()?.parse()?);
+                            } else {
+                                return Err(meta.error("Unknown attribute"));
+                            }
+
+                            Ok(())
+                        })
+                        .unwrap();
+                }
+
+                data
+            })
+            .collect()
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+struct Source<'a> {
+    fields: Vec<&'a Field>,
+}
+
+impl<'a> Source<'a> {
+    fn read(field_data: &[FieldData<'a>]) -> Self {
+        Self {
+            fields: field_data
+                .iter()
+                .filter(|field_data| field_data.modifier.is_none())
+                .map(|field_data| field_data.field)
+                .collect(),
+        }
+    }
+
+    fn write_type(&self) -> TokenStream {
+        match self.fields.as_slice() {
+            [field] => {
+                let r#type = &field.ty;
+
+                quote!(#r#type)
+            }
+            fields => {
+                let types = fields.iter().map(|field| {
+                    let r#type = &field.ty;
+
+                    quote!(#r#type,)
+                });
+
+                quote!((#(#types)*))
+            }
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_values<'a>(
+    field_data: &'a [FieldData<'a>],
+    source: &Source,
+) -> impl Iterator<Item = TokenStream> + 'a {
+    let single = source.fields.len() == 1;
+    let mut source_index = 0;
+
+    field_data
+        .iter()
+        .map(move |field_data| match &field_data.modifier {
+            Some(FieldDataModifier::Default) => {
+                let r#type = &field_data.field.ty;
+
+                quote!(<#r#type as ::core::default::Default>::default())
+            }
+            Some(FieldDataModifier::Value(value)) => quote!(#value),
+            None if single => quote!(value),
+            None => {
+                let index = Index::from(source_index);
+                source_index += 1;
+
+                quote!(value.#index)
+            }
+        })
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_construction(
+    r#impl: TokenStream,
+    fields: &Fields,
+    values: Vec<TokenStream>,
+) -> TokenStream {
+    match fields {
+        Fields::Named(fields) => {
+            let assignments = fields.named.iter().zip(values).map(|(field, value)| {
+                let ident = &field.ident;
+
+                quote!(
+                    #ident: #value
+                )
+            });
+
+            quote!(#r#impl { #(#assignments),* })
+        }
+        Fields::Unnamed(_) => quote!(#r#impl(#(#values),*)),
+        Fields::Unit => r#impl,
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_from_into_impl(
     generics: &Generics,
     name: &Ident,
     r#impl: TokenStream,
-    fields: &FieldsNamed,
+    fields: &Fields,
+    field_data: &[FieldData],
+    source: &Source,
 ) -> TokenStream {
-    let types = fields.named.iter().map(|field| {
-        let r#type = &field.ty;
+    let field = match source.fields.as_slice() {
+        [field] => field,
+        _ => {
+            return Error::new_spanned(fields, "`#[From(into)]` requires exactly one source field")
+                .to_compile_error()
+        }
+    };
 
-        quote!(#r#type,)
-    });
+    let r#type = &field.ty;
 
-    let from_type = quote!((#(#types)*));
+    if is_reflexive(r#type, generics) {
+        return Error::new_spanned(
+            r#type,
+            format!(
+                "`#[From(into)]` conflicts with `impl<T> From<T> for T` because `{}` may be `{}`",
+                quote!(#r#type),
+                name,
+            ),
+        )
+        .to_compile_error();
+    }
 
-    let assignments = fields.named.iter().enumerate().map(|(index, field)| {
-        let ident = &field.ident;
-        let index = Index::from(index);
+    let mut into_generics = generics.clone();
+    into_generics.params.push(parse_quote!(__Into));
+    into_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(__Into: ::core::convert::Into<#r#type>));
 
-        quote!(
-            #ident: value.#index
-        )
-    });
+    let (impl_generics, _type_generics, where_clause) = into_generics.split_for_impl();
+    let (_impl_generics, type_generics, _where_clause) = generics.split_for_impl();
+    let construction =
+        write_construction(r#impl, fields, write_values(field_data, source).collect());
 
-    write_from_impl(
-        generics,
-        name,
-        from_type.clone(),
-        quote!(
-            fn from(value: #from_type) -> Self {
-                #r#impl { #(#assignments),* }
+    quote!(
+        impl #impl_generics ::core::convert::From<__Into> for #name #type_generics #where_clause {
+            fn from(value: __Into) -> Self {
+                let value: #r#type = ::core::convert::Into::into(value);
+
+                #construction
             }
-        ),
+        }
     )
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn write_from_unnamed_fields_impl(
+fn write_from_via_impl(
     generics: &Generics,
     name: &Ident,
     r#impl: TokenStream,
-    fields: &FieldsUnnamed,
+    fields: &Fields,
+    field_data: &[FieldData],
+    source: &Source,
+    via: &Type,
 ) -> TokenStream {
-    let mut field = fields.unnamed.iter();
+    let field = match source.fields.as_slice() {
+        [field] => field,
+        _ => {
+            return Error::new_spanned(fields, "`#[From(via)]` requires exactly one source field")
+                .to_compile_error()
+        }
+    };
+
+    let r#type = &field.ty;
 
-    let (r#type, r#impl) = match (field.next(), field.next()) {
-        (None, None) => (quote!(()), quote!(#r#impl())),
-        (Some(field), None) => {
-            let r#type = &field.ty;
-            let ident = format_ident!("value");
+    let mut via_generics = generics.clone();
+    via_generics.params.push(parse_quote!(__Source));
+    via_generics
+        .make_where_clause()
+        .predicates
+        .push(parse_quote!(#via: ::core::convert::From<__Source>));
 
-            (quote!(#r#type), quote!(#r#impl(#ident)))
+    let (impl_generics, _type_generics, where_clause) = via_generics.split_for_impl();
+    let (_impl_generics, type_generics, _where_clause) = generics.split_for_impl();
+    let construction =
+        write_construction(r#impl, fields, write_values(field_data, source).collect());
+
+    quote!(
+        impl #impl_generics ::core::convert::From<__Source> for #name #type_generics #where_clause {
+            fn from(value: __Source) -> Self {
+                let value = <#via as ::core::convert::From<__Source>>::from(value);
+                let value: #r#type = ::core::convert::From::from(value);
+
+                #construction
+            }
         }
-        _ => {
-            let types = fields.unnamed.iter().map(|field| &field.ty);
-            let impls = fields.unnamed.iter().enumerate().map(|(index, _field)| {
-                let index = Index::from(index);
-                let ident = format_ident!("value");
+    )
+}
 
-                quote!(
-                    #ident.#index
-                )
-            });
+////////////////////////////////////////////////////////////////////////////////////////////////////
 
-            (
-                quote!((#(#types),*)),
-                quote!(
-                    #r#impl(#(#impls),*)
-                ),
-            )
+fn write_from_boxed_impl(
+    generics: &Generics,
+    name: &Ident,
+    r#impl: TokenStream,
+    fields: &Fields,
+    field_data: &[FieldData],
+    source: &Source,
+) -> TokenStream {
+    let r#type = match source.fields.as_slice() {
+        [field] => &field.ty,
+        _ => {
+            return Error::new_spanned(fields, "`#[From(boxed)]` requires exactly one source field")
+                .to_compile_error()
+        }
+    };
+
+    let inner = match read_boxed(r#type) {
+        Some(inner) => inner,
+        None => {
+            return Error::new_spanned(r#type, "`#[From(boxed)]` requires a `Box<T>` field")
+                .to_compile_error()
         }
     };
 
+    if is_reflexive(inner, generics) {
+        return Error::new_spanned(
+            inner,
+            format!(
+                "`#[From(boxed)]` conflicts with `impl<T> From<T> for T` because `{}` may be `{}`",
+                quote!(#inner),
+                name,
+            ),
+        )
+        .to_compile_error();
+    }
+
+    let construction =
+        write_construction(r#impl, fields, write_values(field_data, source).collect());
+
     write_from_impl(
         generics,
         name,
-        r#type.clone(),
+        quote!(#inner),
         quote!(
-            fn from(value: #r#type) -> Self {
-                #r#impl
+            fn from(value: #inner) -> Self {
+                let value: #r#type = ::core::convert::From::from(value);
+
+                #construction
             }
         ),
     )
@@ -115,36 +468,232 @@ fn write_from_unnamed_fields_impl(
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn write_from_unit_fields_impl(
+fn write_from_fields_impl(
     generics: &Generics,
     name: &Ident,
     r#impl: TokenStream,
+    fields: &Fields,
+    options: &Options,
 ) -> TokenStream {
-    write_from_impl(
+    let field_data = FieldData::read(fields);
+    let source = Source::read(&field_data);
+
+    if options.into && options.via.is_some() {
+        return Error::new_spanned(fields, "`#[From(into)]` and `#[From(via)]` are exclusive")
+            .to_compile_error();
+    }
+
+    if options.is_generic() && (options.r#ref || options.boxed) {
+        return Error::new_spanned(
+            fields,
+            "`#[From(ref)]` and `#[From(boxed)]` cannot be combined with `#[From(into)]` or `#[From(via)]`",
+        )
+        .to_compile_error();
+    }
+
+    if options.into {
+        return write_from_into_impl(generics, name, r#impl, fields, &field_data, &source);
+    }
+
+    if let Some(via) = &options.via {
+        return write_from_via_impl(generics, name, r#impl, fields, &field_data, &source, via);
+    }
+
+    let from_type = source.write_type();
+    let construction = write_construction(
+        r#impl.clone(),
+        fields,
+        write_values(&field_data, &source).collect(),
+    );
+
+    let from_impl = write_from_impl(
         generics,
         name,
-        quote!(()),
+        from_type.clone(),
         quote!(
-            fn from(value: ()) -> Self {
-                #r#impl
+            fn from(value: #from_type) -> Self {
+                #construction
             }
         ),
+    );
+
+    let ref_impl = match options.r#ref {
+        true => {
+            let mut ref_generics = generics.clone();
+            ref_generics.params.insert(0, parse_quote!('__from));
+            ref_generics
+                .make_where_clause()
+                .predicates
+                .push(parse_quote!(#from_type: ::core::clone::Clone));
+
+            let (impl_generics, _type_generics, where_clause) = ref_generics.split_for_impl();
+            let (_impl_generics, type_generics, _where_clause) = generics.split_for_impl();
+
+            quote!(
+                impl #impl_generics ::core::convert::From<&'__from #from_type> for #name #type_generics #where_clause {
+                    fn from(value: &'__from #from_type) -> Self {
+                        let value: #from_type = ::core::clone::Clone::clone(value);
+
+                        #construction
+                    }
+                }
+            )
+        }
+        false => quote!(),
+    };
+
+    let boxed_impl = match options.boxed {
+        true => write_from_boxed_impl(generics, name, r#impl, fields, &field_data, &source),
+        false => quote!(),
+    };
+
+    quote!(
+        #from_impl
+        #ref_impl
+        #boxed_impl
     )
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
-fn write_from_fields_impl(
+fn write_destructuring(
+    r#impl: TokenStream,
+    field_data: &[FieldData],
+) -> (TokenStream, TokenStream) {
+    let mut bindings = Vec::new();
+
+    let patterns = field_data
+        .iter()
+        .enumerate()
+        .map(|(field_index, field_data)| {
+            let binding = format_ident!("value{}", field_index);
+
+            let pattern = match field_data.modifier {
+                Some(_) => quote!(_),
+                None => {
+                    bindings.push(binding.clone());
+
+                    quote!(#binding)
+                }
+            };
+
+            match &field_data.field.ident {
+                Some(ident) => quote!(#ident: #pattern),
+                None => pattern,
+            }
+        })
+        .collect::<Vec<_>>();
+
+    let pattern = match field_data.first().map(|field_data| &field_data.field.ident) {
+        Some(Some(_)) => quote!(#r#impl { #(#patterns),* }),
+        Some(None) => quote!(#r#impl(#(#patterns),*)),
+        None => quote!(#r#impl { .. }),
+    };
+
+    let value = match bindings.as_slice() {
+        [binding] => quote!(#binding),
+        bindings => quote!((#(#bindings,)*)),
+    };
+
+    (pattern, value)
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_reverse_generic_error(source: &Source, generics: &Generics) -> Option<TokenStream> {
+    let r#type = match source.fields.as_slice() {
+        [field] if is_reflexive(&field.ty, generics) => &field.ty,
+        _ => return None,
+    };
+
+    Some(
+        Error::new_spanned(
+            r#type,
+            format!(
+                "`#[From(reverse)]` cannot implement a conversion into the type parameter `{}`",
+                quote!(#r#type),
+            ),
+        )
+        .to_compile_error(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_struct_options_error(fields: &Fields) -> Option<TokenStream> {
+    let field_data = FieldData::read(fields)
+        .into_iter()
+        .find(|field_data| field_data.rename.is_some() || field_data.with.is_some())?;
+
+    Some(
+        Error::new_spanned(
+            field_data.field,
+            "`#[From(rename)]` and `#[From(with)]` require `#[From(struct = ...)]`",
+        )
+        .to_compile_error(),
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_reverse_from_impl(generics: &Generics, name: &Ident, fields: &Fields) -> TokenStream {
+    let field_data = FieldData::read(fields);
+    let source = Source::read(&field_data);
+
+    if let Some(error) = write_reverse_generic_error(&source, generics) {
+        return error;
+    }
+    let to_type = source.write_type();
+    let (pattern, value) = write_destructuring(quote!(#name), &field_data);
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::core::convert::From<#name #type_generics> for #to_type #where_clause {
+            fn from(value: #name #type_generics) -> Self {
+                let #pattern = value;
+
+                #value
+            }
+        }
+    )
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_reverse_try_from_impl(
     generics: &Generics,
     name: &Ident,
-    r#impl: TokenStream,
-    fields: &Fields,
+    variant: &Variant,
 ) -> TokenStream {
-    match fields {
-        Fields::Named(fields) => write_from_named_fields_impl(generics, name, r#impl, fields),
-        Fields::Unnamed(fields) => write_from_unnamed_fields_impl(generics, name, r#impl, fields),
-        Fields::Unit => write_from_unit_fields_impl(generics, name, r#impl),
+    let field_data = FieldData::read(&variant.fields);
+    let source = Source::read(&field_data);
+
+    if source.fields.is_empty() {
+        return quote!();
     }
+
+    if let Some(error) = write_reverse_generic_error(&source, generics) {
+        return error;
+    }
+
+    let variant_ident = &variant.ident;
+    let to_type = source.write_type();
+    let (pattern, value) = write_destructuring(quote!(#name::#variant_ident), &field_data);
+    let (impl_generics, type_generics, where_clause) = generics.split_for_impl();
+
+    quote!(
+        impl #impl_generics ::core::convert::TryFrom<#name #type_generics> for #to_type #where_clause {
+            type Error = #name #type_generics;
+
+            #[allow(unreachable_patterns)]
+            fn try_from(value: #name #type_generics) -> ::core::result::Result<Self, Self::Error> {
+                match value {
+                    #pattern => ::core::result::Result::Ok(#value),
+                    value => ::core::result::Result::Err(value),
+                }
+            }
+        }
+    )
 }
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
@@ -158,6 +707,7 @@ enum EnumVariantDataModifier {
 
 struct EnumVariantData {
     modifier: Option<EnumVariantDataModifier>,
+    options: Options,
 }
 
 impl EnumVariantData {
@@ -171,9 +721,11 @@ impl EnumVariantData {
 struct EnumData<'a> {
     ast: &'a ItemEnum,
     variants: Vec<EnumVariantData>,
+    options: Options,
     named: bool,
     unnamed: bool,
     unit: bool,
+    repr: bool,
 }
 
 impl<'a> EnumData<'a> {
@@ -189,13 +741,19 @@ impl<'a> EnumData<'a> {
         self.unit = true
     }
 
+    fn enable_repr(&mut self) {
+        self.repr = true
+    }
+
     fn read(ast: &'a ItemEnum) -> Self {
         let mut data = Self {
             ast,
             variants: Vec::with_capacity(ast.variants.len()),
+            options: Options::default(),
             named: false,
             unnamed: false,
             unit: false,
+            repr: false,
         };
 
         if let Some(attribute) = ast
@@ -223,13 +781,22 @@ impl<'a> EnumData<'a> {
                         data.enable_unit();
                     }
 
+                    if meta.path.is_ident("repr") {
+                        data.enable_repr();
+                    }
+
+                    data.options.read(&meta)?;
+
                     Ok(())
                 })
                 .unwrap();
         }
 
         for variant in &ast.variants {
-            let mut variant_data = EnumVariantData { modifier: None };
+            let mut variant_data = EnumVariantData {
+                modifier: None,
+                options: Options::default(),
+            };
 
             if let Some(attribute) = variant
                 .attrs
@@ -249,6 +816,11 @@ impl<'a> EnumData<'a> {
                                 variant_data.set_modifier(EnumVariantDataModifier::Skipped);
                             }
 
+                            if variant_data.options.read(&meta)? && variant_data.modifier.is_none()
+                            {
+                                variant_data.set_modifier(EnumVariantDataModifier::Enabled);
+                            }
+
                             Ok(())
                         })
                         .unwrap();
@@ -261,7 +833,149 @@ impl<'a> EnumData<'a> {
         data
     }
 
+    fn write_repr(&self) -> TokenStream {
+        let name = &self.ast.ident;
+
+        let mut repr = None;
+
+        for attribute in self
+            .ast
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("repr"))
+        {
+            attribute
+                .parse_nested_meta(|meta| {
+                    if let Some(ident) = meta.path.get_ident() {
+                        if matches!(
+                            ident.to_string().as_str(),
+                            "u8" | "u16"
+                                | "u32"
+                                | "u64"
+                                | "u128"
+                                | "usize"
+                                | "i8"
+                                | "i16"
+                                | "i32"
+                                | "i64"
+                                | "i128"
+                                | "isize"
+                        ) {
+                            repr = Some(ident.clone());
+                        }
+                    }
+
+                    if meta.input.peek(Paren) {
+                        let content;
+                        parenthesized!(content in meta.input);
+                        content.parse::<TokenStream>()?;
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        }
+
+        let repr = match repr {
+            Some(repr) => repr,
+            None => {
+                return Error::new_spanned(name, "`#[From(repr)]` requires an integer `#[repr]`")
+                    .to_compile_error()
+            }
+        };
+
+        if let Some(variant) = self
+            .ast
+            .variants
+            .iter()
+            .find(|variant| !matches!(variant.fields, Fields::Unit))
+        {
+            return Error::new_spanned(variant, "`#[From(repr)]` requires unit variants")
+                .to_compile_error();
+        }
+
+        let visibility = &self.ast.vis;
+        let error = format_ident!("{}ReprError", name);
+        let message = format!("Unknown {} discriminant {{}}", name);
+        let (impl_generics, type_generics, where_clause) = self.ast.generics.split_for_impl();
+
+        let variants = self.ast.variants.iter().map(|variant| {
+            let variant_ident = &variant.ident;
+
+            quote!(
+                if value == Self::#variant_ident as #repr {
+                    return ::core::result::Result::Ok(Self::#variant_ident);
+                }
+            )
+        });
+
+        quote!(
+            #[derive(
+                ::core::fmt::Debug,
+                ::core::clone::Clone,
+                ::core::marker::Copy,
+                ::core::cmp::PartialEq,
+                ::core::cmp::Eq,
+            )]
+            #visibility struct #error(pub #repr);
+
+            impl ::core::fmt::Display for #error {
+                fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+                    ::core::write!(formatter, #message, self.0)
+                }
+            }
+
+            impl ::core::error::Error for #error {}
+
+            impl #impl_generics ::core::convert::TryFrom<#repr> for #name #type_generics #where_clause {
+                type Error = #error;
+
+                fn try_from(value: #repr) -> ::core::result::Result<Self, Self::Error> {
+                    #(#variants)*
+
+                    ::core::result::Result::Err(#error(value))
+                }
+            }
+
+            impl #impl_generics ::core::convert::From<#name #type_generics> for #repr #where_clause {
+                fn from(value: #name #type_generics) -> Self {
+                    value as #repr
+                }
+            }
+        )
+    }
+
     fn write(self) -> TokenStream {
+        if let Some(intos) = self.write_intos() {
+            return intos;
+        }
+
+        if let Some(vias) = self.write_vias() {
+            return vias;
+        }
+
+        if let Some(foreign_vias) = self.write_foreign_vias() {
+            return foreign_vias;
+        }
+
+        if let Some(duplicates) = self.write_duplicates() {
+            return duplicates;
+        }
+
+        if let Some(error) = self
+            .ast
+            .variants
+            .iter()
+            .find_map(|variant| write_struct_options_error(&variant.fields))
+        {
+            return error;
+        }
+
+        let repr = match self.repr {
+            true => self.write_repr(),
+            false => quote!(),
+        };
+
         let variants = self
             .ast
             .variants
@@ -270,13 +984,12 @@ impl<'a> EnumData<'a> {
             .flat_map(|(variant_index, variant)| self.write_variant(variant_index, variant));
 
         quote!(
+            #repr
             #(#variants)*
         )
     }
 
-    fn write_variant(&self, variant_index: usize, variant: &Variant) -> Option<TokenStream> {
-        let variant_ident = &variant.ident;
-
+    fn get_variant_data(&self, variant_index: usize) -> &EnumVariantData {
         #[coverage(off)]
         fn get_variant_data<'a>(
             data: &'a EnumData<'a>,
@@ -288,13 +1001,17 @@ impl<'a> EnumData<'a> {
             }
         }
 
-        let variant_data = get_variant_data(self, variant_index);
+        get_variant_data(self, variant_index)
+    }
+
+    fn is_enabled(&self, variant_index: usize, variant: &Variant) -> bool {
+        let variant_data = self.get_variant_data(variant_index);
 
         if matches!(
             variant_data.modifier,
             Some(EnumVariantDataModifier::Skipped)
         ) {
-            return None;
+            return false;
         }
 
         let enabled_with_all = match &variant.fields {
@@ -303,20 +1020,220 @@ impl<'a> EnumData<'a> {
             Fields::Unit => self.unit,
         };
 
-        if !enabled_with_all
-            && !matches!(
+        enabled_with_all
+            || matches!(
                 variant_data.modifier,
                 Some(EnumVariantDataModifier::Enabled)
             )
-        {
+    }
+
+    fn write_intos(&self) -> Option<TokenStream> {
+        let enabled = self
+            .ast
+            .variants
+            .iter()
+            .enumerate()
+            .filter(|(variant_index, variant)| self.is_enabled(*variant_index, variant))
+            .collect::<Vec<_>>();
+
+        enabled
+            .iter()
+            .filter(|(variant_index, _variant)| {
+                self.get_variant_data(*variant_index)
+                    .options
+                    .or(&self.options)
+                    .into
+            })
+            .map(|(_variant_index, variant)| {
+                let others = enabled
+                    .iter()
+                    .filter(|(_other_index, other)| other.ident != variant.ident)
+                    .map(|(_other_index, other)| format!("`{}`", other.ident))
+                    .collect::<Vec<_>>();
+
+                (variant, others)
+            })
+            .filter(|(_variant, others)| !others.is_empty())
+            .map(|(variant, others)| {
+                Error::new_spanned(
+                    &variant.ident,
+                    format!(
+                        "Variant `{}` uses `#[From(into)]` which conflicts with {}, add `#[From(skip)]` to them or remove `#[From(into)]`",
+                        variant.ident,
+                        others.join(", "),
+                    ),
+                )
+            })
+            .reduce(|mut error, other| {
+                error.combine(other);
+                error
+            })
+            .map(|error| error.to_compile_error())
+    }
+
+    fn write_vias(&self) -> Option<TokenStream> {
+        let mut variants = self
+            .ast
+            .variants
+            .iter()
+            .enumerate()
+            .filter(|(variant_index, variant)| {
+                self.is_enabled(*variant_index, variant)
+                    && self
+                        .get_variant_data(*variant_index)
+                        .options
+                        .or(&self.options)
+                        .via
+                        .is_some()
+            })
+            .map(|(_variant_index, variant)| variant);
+
+        let first = variants.next()?;
+        let second = variants.next()?;
+
+        Some(
+            Error::new_spanned(
+                &second.ident,
+                format!(
+                    "Variants `{}` and `{}` both use `#[From(via)]`, only one is allowed per type",
+                    first.ident, second.ident,
+                ),
+            )
+            .to_compile_error(),
+        )
+    }
+
+    fn write_foreign_vias(&self) -> Option<TokenStream> {
+        let enabled = self
+            .ast
+            .variants
+            .iter()
+            .enumerate()
+            .filter(|(variant_index, variant)| self.is_enabled(*variant_index, variant))
+            .collect::<Vec<_>>();
+
+        let (variant, via) = enabled.iter().find_map(|(variant_index, variant)| {
+            let via = self
+                .get_variant_data(*variant_index)
+                .options
+                .or(&self.options)
+                .via?;
+
+            Some((variant, via))
+        })?;
+
+        if !is_foreign(&via) {
+            return None;
+        }
+
+        let others = enabled
+            .iter()
+            .filter(|(_other_index, other)| other.ident != variant.ident)
+            .map(|(_other_index, other)| format!("`{}`", other.ident))
+            .collect::<Vec<_>>();
+
+        if others.is_empty() {
             return None;
         }
 
-        Some(write_from_fields_impl(
+        Some(
+            Error::new_spanned(
+                &via,
+                format!(
+                    "Variant `{}` converts via `{}` which conflicts with {}, `#[From(via)]` next to other variants requires a type of this crate",
+                    variant.ident,
+                    quote!(#via),
+                    others.join(", "),
+                ),
+            )
+            .to_compile_error(),
+        )
+    }
+
+    fn write_duplicates(&self) -> Option<TokenStream> {
+        let mut sources: Vec<(String, &Variant)> = Vec::new();
+        let mut error: Option<Error> = None;
+
+        for (variant_index, variant) in self.ast.variants.iter().enumerate() {
+            if !self.is_enabled(variant_index, variant)
+                || self
+                    .get_variant_data(variant_index)
+                    .options
+                    .or(&self.options)
+                    .is_generic()
+            {
+                continue;
+            }
+
+            let field_data = FieldData::read(&variant.fields);
+            let source = Source::read(&field_data);
+            let mut types = vec![source.write_type().to_string()];
+
+            if self
+                .get_variant_data(variant_index)
+                .options
+                .or(&self.options)
+                .boxed
+            {
+                if let Some(inner) = source
+                    .fields
+                    .first()
+                    .and_then(|field| read_boxed(&field.ty))
+                {
+                    types.push(quote!(#inner).to_string());
+                }
+            }
+
+            for source in types {
+                match sources.iter().find(|(other, _variant)| *other == source) {
+                    Some((_source, other)) => {
+                        let duplicate = Error::new_spanned(
+                            &variant.ident,
+                            format!(
+                                "Variants `{}` and `{}` both convert from `{}`, add `#[From(skip)]` to one of them",
+                                other.ident, variant.ident, source,
+                            ),
+                        );
+
+                        match &mut error {
+                            Some(error) => error.combine(duplicate),
+                            None => error = Some(duplicate),
+                        }
+                    }
+                    None => sources.push((source, variant)),
+                }
+            }
+        }
+
+        error.map(|error| error.to_compile_error())
+    }
+
+    fn write_variant(&self, variant_index: usize, variant: &Variant) -> Option<TokenStream> {
+        let variant_ident = &variant.ident;
+        let variant_data = self.get_variant_data(variant_index);
+
+        if !self.is_enabled(variant_index, variant) {
+            return None;
+        }
+
+        let options = variant_data.options.or(&self.options);
+
+        let from_impl = write_from_fields_impl(
             &self.ast.generics,
             &self.ast.ident,
             quote!(Self::#variant_ident),
             &variant.fields,
+            &options,
+        );
+
+        let reverse_impl = match options.reverse {
+            true => write_reverse_try_from_impl(&self.ast.generics, &self.ast.ident, variant),
+            false => quote!(),
+        };
+
+        Some(quote!(
+            #from_impl
+            #reverse_impl
         ))
     }
 }
@@ -325,19 +1242,286 @@ impl<'a> EnumData<'a> {
 
 struct StructData<'a> {
     ast: &'a ItemStruct,
+    options: Options,
+    iter: bool,
+    structs: Vec<Type>,
 }
 
 impl<'a> StructData<'a> {
+    fn enable_iter(&mut self) {
+        self.iter = true
+    }
+
     fn read(ast: &'a ItemStruct) -> Self {
-        Self { ast }
+        let mut data = Self {
+            ast,
+            options: Options::default(),
+            iter: false,
+            structs: Vec::new(),
+        };
+
+        for attribute in ast
+            .attrs
+            .iter()
+            .filter(|attribute| attribute.path().is_ident("From"))
+        {
+            attribute
+                .parse_nested_meta(|meta| {
+                    if meta.path.is_ident("iter") {
+                        data.enable_iter();
+                    } else if meta.path.is_ident("struct") {
+                        data.structs.push(meta.value()?.parse()?);
+                    } else if !data.options.read(&meta)? {
+                        return Err(meta.error("Unknown attribute"));
+                    }
+
+                    Ok(())
+                })
+                .unwrap();
+        }
+
+        data
     }
 
     fn write(self) -> TokenStream {
-        write_from_fields_impl(
+        if let (true, Some(r#struct)) = (self.options.into, self.structs.first()) {
+            return Error::new_spanned(
+                r#struct,
+                "`#[From(into)]` conflicts with `#[From(struct = ...)]`, use only one of them",
+            )
+            .to_compile_error();
+        }
+
+        if self.structs.is_empty() {
+            if let Some(error) = write_struct_options_error(&self.ast.fields) {
+                return error;
+            }
+        }
+
+        if let (Some(via), false) = (&self.options.via, self.structs.is_empty()) {
+            if is_foreign(via) {
+                return Error::new_spanned(
+                    via,
+                    "`#[From(via)]` next to `#[From(struct = ...)]` requires a type of this crate",
+                )
+                .to_compile_error();
+            }
+        }
+
+        let from_impl = write_from_fields_impl(
             &self.ast.generics,
             &self.ast.ident,
             quote!(Self),
             &self.ast.fields,
+            &self.options,
+        );
+
+        let reverse_impl = match self.options.reverse {
+            true => write_reverse_from_impl(&self.ast.generics, &self.ast.ident, &self.ast.fields),
+            false => quote!(),
+        };
+
+        let iter_impl = match self.iter {
+            true => self.write_iter(),
+            false => quote!(),
+        };
+
+        let struct_impls = self
+            .structs
+            .iter()
+            .map(|r#struct| self.write_struct(r#struct));
+
+        quote!(
+            #from_impl
+            #reverse_impl
+            #iter_impl
+            #(#struct_impls)*
+        )
+    }
+
+    fn write_struct(&self, r#struct: &Type) -> TokenStream {
+        let fields = match &self.ast.fields {
+            Fields::Named(fields) => fields,
+            fields => {
+                return Error::new_spanned(fields, "`#[From(struct)]` requires named fields")
+                    .to_compile_error()
+            }
+        };
+
+        let field_data = FieldData::read(&self.ast.fields);
+
+        let assignments = fields
+            .named
+            .iter()
+            .zip(&field_data)
+            .map(|(field, field_data)| {
+                let ident = &field.ident;
+
+                let value = match &field_data.modifier {
+                    Some(FieldDataModifier::Default) => {
+                        let r#type = &field.ty;
+
+                        quote!(<#r#type as ::core::default::Default>::default())
+                    }
+                    Some(FieldDataModifier::Value(value)) => quote!(#value),
+                    None => {
+                        let source = field_data.rename.as_ref().or(ident.as_ref());
+
+                        match &field_data.with {
+                            Some(with) => quote_spanned!(with.span()=> #with(value.#source)),
+                            None => quote_spanned!(source.span()=> value.#source),
+                        }
+                    }
+                };
+
+                quote!(
+                    #ident: #value
+                )
+            });
+
+        write_from_impl(
+            &self.ast.generics,
+            &self.ast.ident,
+            quote!(#r#struct),
+            quote!(
+                fn from(value: #r#struct) -> Self {
+                    Self { #(#assignments),* }
+                }
+            ),
+        )
+    }
+
+    fn write_iter(&self) -> TokenStream {
+        let name = &self.ast.ident;
+        let field_data = FieldData::read(&self.ast.fields);
+        let source = Source::read(&field_data);
+
+        let mut sources = field_data
+            .iter()
+            .enumerate()
+            .filter(|(_field_index, field_data)| field_data.modifier.is_none());
+
+        let (field_index, field) = match (sources.next(), sources.next()) {
+            (Some((field_index, field_data)), None) => (field_index, field_data.field),
+            _ => {
+                return Error::new_spanned(
+                    &self.ast.fields,
+                    "`#[From(iter)]` requires exactly one source field",
+                )
+                .to_compile_error()
+            }
+        };
+
+        let r#type = &field.ty;
+        let member = match &field.ident {
+            Some(ident) => quote!(#ident),
+            None => {
+                let index = Index::from(field_index);
+
+                quote!(#index)
+            }
+        };
+
+        let construction = write_construction(
+            quote!(Self),
+            &self.ast.fields,
+            write_values(&field_data, &source).collect(),
+        );
+
+        let write_generics = |parameter: GenericParam, predicate: WherePredicate| {
+            let mut generics = self.ast.generics.clone();
+            match &parameter {
+                GenericParam::Lifetime(_lifetime) => generics.params.insert(0, parameter),
+                _ => generics.params.push(parameter),
+            }
+            generics.make_where_clause().predicates.push(predicate);
+            generics
+        };
+
+        let (_impl_generics, type_generics, _where_clause) = self.ast.generics.split_for_impl();
+
+        let item_generics = write_generics(
+            parse_quote!(__Item),
+            parse_quote!(#r#type: ::core::iter::FromIterator<__Item>),
+        );
+        let (impl_generics, _type_generics, where_clause) = item_generics.split_for_impl();
+
+        let from_iterator_impl = quote!(
+            impl #impl_generics ::core::iter::FromIterator<__Item> for #name #type_generics #where_clause {
+                fn from_iter<__Iterator>(iterator: __Iterator) -> Self
+                where
+                    __Iterator: ::core::iter::IntoIterator<Item = __Item>,
+                {
+                    let value: #r#type = ::core::iter::FromIterator::from_iter(iterator);
+
+                    #construction
+                }
+            }
+        );
+
+        let item_generics = write_generics(
+            parse_quote!(__Item),
+            parse_quote!(#r#type: ::core::iter::Extend<__Item>),
+        );
+        let (impl_generics, _type_generics, where_clause) = item_generics.split_for_impl();
+
+        let extend_impl = quote!(
+            impl #impl_generics ::core::iter::Extend<__Item> for #name #type_generics #where_clause {
+                fn extend<__Iterator>(&mut self, iterator: __Iterator)
+                where
+                    __Iterator: ::core::iter::IntoIterator<Item = __Item>,
+                {
+                    ::core::iter::Extend::extend(&mut self.#member, iterator)
+                }
+            }
+        );
+
+        let mut owned_generics = self.ast.generics.clone();
+        owned_generics
+            .make_where_clause()
+            .predicates
+            .push(parse_quote!(#r#type: ::core::iter::IntoIterator));
+        let (impl_generics, _type_generics, where_clause) = owned_generics.split_for_impl();
+
+        let owned_impl = quote!(
+            impl #impl_generics ::core::iter::IntoIterator for #name #type_generics #where_clause {
+                type Item = <#r#type as ::core::iter::IntoIterator>::Item;
+                type IntoIter = <#r#type as ::core::iter::IntoIterator>::IntoIter;
+
+                fn into_iter(self) -> Self::IntoIter {
+                    ::core::iter::IntoIterator::into_iter(self.#member)
+                }
+            }
+        );
+
+        let borrowed_impls = [
+            (quote!(&'__iter), quote!(&self.#member)),
+            (quote!(&'__iter mut), quote!(&mut self.#member)),
+        ]
+        .map(|(reference, value)| {
+            let reference_generics = write_generics(
+                parse_quote!('__iter),
+                parse_quote!(#reference #r#type: ::core::iter::IntoIterator),
+            );
+            let (impl_generics, _type_generics, where_clause) = reference_generics.split_for_impl();
+
+            quote!(
+                impl #impl_generics ::core::iter::IntoIterator for #reference #name #type_generics #where_clause {
+                    type Item = <#reference #r#type as ::core::iter::IntoIterator>::Item;
+                    type IntoIter = <#reference #r#type as ::core::iter::IntoIterator>::IntoIter;
+
+                    fn into_iter(self) -> Self::IntoIter {
+                        ::core::iter::IntoIterator::into_iter(#value)
+                    }
+                }
+            )
+        });
+
+        quote!(
+            #from_iterator_impl
+            #extend_impl
+            #owned_impl
+            #(#borrowed_impls)*
         )
     }
 }
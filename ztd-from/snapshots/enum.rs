@@ -0,0 +1,18 @@
+impl ::core::convert::From<()> for Enum {
+    fn from(value: ()) -> Self {
+        Self::First
+    }
+}
+impl ::core::convert::From<std::io::Error> for Enum {
+    fn from(value: std::io::Error) -> Self {
+        Self::Second(value)
+    }
+}
+impl ::core::convert::From<(String, usize)> for Enum {
+    fn from(value: (String, usize)) -> Self {
+        Self::Third {
+            first: value.0,
+            second: value.1,
+        }
+    }
+}
@@ -0,0 +1,25 @@
+impl<T> ::core::convert::From<String> for Error<T> {
+    fn from(value: String) -> Self {
+        Self::Message(value)
+    }
+}
+impl<'__from, T> ::core::convert::From<&'__from String> for Error<T>
+where
+    String: ::core::clone::Clone,
+{
+    fn from(value: &'__from String) -> Self {
+        let value: String = ::core::clone::Clone::clone(value);
+        Self::Message(value)
+    }
+}
+impl<T> ::core::convert::From<Box<Vec<T>>> for Error<T> {
+    fn from(value: Box<Vec<T>>) -> Self {
+        Self::Large(value)
+    }
+}
+impl<T> ::core::convert::From<Vec<T>> for Error<T> {
+    fn from(value: Vec<T>) -> Self {
+        let value: Box<Vec<T>> = ::core::convert::From::from(value);
+        Self::Large(value)
+    }
+}
@@ -0,0 +1,31 @@
+#[derive(
+    ::core::fmt::Debug,
+    ::core::clone::Clone,
+    ::core::marker::Copy,
+    ::core::cmp::PartialEq,
+    ::core::cmp::Eq,
+)]
+pub struct OpcodeReprError(pub u16);
+impl ::core::fmt::Display for OpcodeReprError {
+    fn fmt(&self, formatter: &mut ::core::fmt::Formatter<'_>) -> ::core::fmt::Result {
+        ::core::write!(formatter, "Unknown Opcode discriminant {}", self.0)
+    }
+}
+impl ::core::error::Error for OpcodeReprError {}
+impl ::core::convert::TryFrom<u16> for Opcode {
+    type Error = OpcodeReprError;
+    fn try_from(value: u16) -> ::core::result::Result<Self, Self::Error> {
+        if value == Self::Ping as u16 {
+            return ::core::result::Result::Ok(Self::Ping);
+        }
+        if value == Self::Pong as u16 {
+            return ::core::result::Result::Ok(Self::Pong);
+        }
+        ::core::result::Result::Err(OpcodeReprError(value))
+    }
+}
+impl ::core::convert::From<Opcode> for u16 {
+    fn from(value: Opcode) -> Self {
+        value as u16
+    }
+}
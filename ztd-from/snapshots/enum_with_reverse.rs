@@ -0,0 +1,40 @@
+impl<T> ::core::convert::From<String> for Event<T> {
+    fn from(value: String) -> Self {
+        Self::Message(value)
+    }
+}
+impl<T> ::core::convert::TryFrom<Event<T>> for String {
+    type Error = Event<T>;
+    #[allow(unreachable_patterns)]
+    fn try_from(value: Event<T>) -> ::core::result::Result<Self, Self::Error> {
+        match value {
+            Event::Message(value0) => ::core::result::Result::Ok(value0),
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl<T> ::core::convert::From<Vec<T>> for Event<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self::Batch {
+            values: value,
+            length: <usize as ::core::default::Default>::default(),
+        }
+    }
+}
+impl<T> ::core::convert::TryFrom<Event<T>> for Vec<T> {
+    type Error = Event<T>;
+    #[allow(unreachable_patterns)]
+    fn try_from(value: Event<T>) -> ::core::result::Result<Self, Self::Error> {
+        match value {
+            Event::Batch { values: value0, length: _ } => {
+                ::core::result::Result::Ok(value0)
+            }
+            value => ::core::result::Result::Err(value),
+        }
+    }
+}
+impl<T> ::core::convert::From<()> for Event<T> {
+    fn from(value: ()) -> Self {
+        Self::Close
+    }
+}
@@ -0,0 +1,15 @@
+impl<__Source> ::core::convert::From<__Source> for Error
+where
+    ParseError: ::core::convert::From<__Source>,
+{
+    fn from(value: __Source) -> Self {
+        let value = <ParseError as ::core::convert::From<__Source>>::from(value);
+        let value: ParseError = ::core::convert::From::from(value);
+        Self::Parse(value)
+    }
+}
+impl ::core::convert::From<std::io::Error> for Error {
+    fn from(value: std::io::Error) -> Self {
+        Self::Io(value)
+    }
+}
@@ -0,0 +1,8 @@
+impl ::core::convert::From<(String, usize)> for Struct {
+    fn from(value: (String, usize)) -> Self {
+        Self {
+            first: value.0,
+            second: value.1,
+        }
+    }
+}
@@ -0,0 +1,9 @@
+impl ::core::convert::From<String> for Struct {
+    fn from(value: String) -> Self {
+        Self {
+            first: value,
+            second: <usize as ::core::default::Default>::default(),
+            third: String::from("third"),
+        }
+    }
+}
@@ -0,0 +1,12 @@
+impl<'a, __Into> ::core::convert::From<__Into> for Name<'a>
+where
+    __Into: ::core::convert::Into<std::borrow::Cow<'a, str>>,
+{
+    fn from(value: __Into) -> Self {
+        let value: std::borrow::Cow<'a, str> = ::core::convert::Into::into(value);
+        Self {
+            value: value,
+            length: <usize as ::core::default::Default>::default(),
+        }
+    }
+}
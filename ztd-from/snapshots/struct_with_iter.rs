@@ -0,0 +1,58 @@
+impl<T> ::core::convert::From<Vec<T>> for Names<T> {
+    fn from(value: Vec<T>) -> Self {
+        Self(value)
+    }
+}
+impl<T, __Item> ::core::iter::FromIterator<__Item> for Names<T>
+where
+    Vec<T>: ::core::iter::FromIterator<__Item>,
+{
+    fn from_iter<__Iterator>(iterator: __Iterator) -> Self
+    where
+        __Iterator: ::core::iter::IntoIterator<Item = __Item>,
+    {
+        let value: Vec<T> = ::core::iter::FromIterator::from_iter(iterator);
+        Self(value)
+    }
+}
+impl<T, __Item> ::core::iter::Extend<__Item> for Names<T>
+where
+    Vec<T>: ::core::iter::Extend<__Item>,
+{
+    fn extend<__Iterator>(&mut self, iterator: __Iterator)
+    where
+        __Iterator: ::core::iter::IntoIterator<Item = __Item>,
+    {
+        ::core::iter::Extend::extend(&mut self.0, iterator)
+    }
+}
+impl<T> ::core::iter::IntoIterator for Names<T>
+where
+    Vec<T>: ::core::iter::IntoIterator,
+{
+    type Item = <Vec<T> as ::core::iter::IntoIterator>::Item;
+    type IntoIter = <Vec<T> as ::core::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        ::core::iter::IntoIterator::into_iter(self.0)
+    }
+}
+impl<'__iter, T> ::core::iter::IntoIterator for &'__iter Names<T>
+where
+    &'__iter Vec<T>: ::core::iter::IntoIterator,
+{
+    type Item = <&'__iter Vec<T> as ::core::iter::IntoIterator>::Item;
+    type IntoIter = <&'__iter Vec<T> as ::core::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        ::core::iter::IntoIterator::into_iter(&self.0)
+    }
+}
+impl<'__iter, T> ::core::iter::IntoIterator for &'__iter mut Names<T>
+where
+    &'__iter mut Vec<T>: ::core::iter::IntoIterator,
+{
+    type Item = <&'__iter mut Vec<T> as ::core::iter::IntoIterator>::Item;
+    type IntoIter = <&'__iter mut Vec<T> as ::core::iter::IntoIterator>::IntoIter;
+    fn into_iter(self) -> Self::IntoIter {
+        ::core::iter::IntoIterator::into_iter(&mut self.0)
+    }
+}
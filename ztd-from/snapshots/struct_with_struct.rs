@@ -0,0 +1,20 @@
+impl ::core::convert::From<(String, String, u64)> for User {
+    fn from(value: (String, String, u64)) -> Self {
+        Self {
+            name: value.0,
+            email: value.1,
+            age: value.2,
+            groups: <Vec<String> as ::core::default::Default>::default(),
+        }
+    }
+}
+impl ::core::convert::From<UserDto> for User {
+    fn from(value: UserDto) -> Self {
+        Self {
+            name: value.name,
+            email: value.mail,
+            age: u64::from(value.age),
+            groups: <Vec<String> as ::core::default::Default>::default(),
+        }
+    }
+}
@@ -0,0 +1,5 @@
+impl<T> ::core::convert::From<T> for TupleStruct<T> {
+    fn from(value: T) -> Self {
+        Self(value)
+    }
+}
@@ -1,6 +1,7 @@
 use quote::quote;
 use ztd_from::From;
 use ztd_from_macro::Macro;
+use ztd_test::assert_expansion;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -362,3 +363,981 @@ fn union_should_panic() {
         .into(),
     );
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct() {
+    assert_expansion(
+        "struct",
+        Macro::handle(quote!(
+            #[derive(From)]
+            struct Struct {
+                first: String,
+                second: usize,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_tuple_struct() {
+    assert_expansion(
+        "tuple_struct",
+        Macro::handle(quote!(
+            #[derive(From)]
+            struct TupleStruct<T>(T);
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_enum() {
+    assert_expansion(
+        "enum",
+        Macro::handle(quote!(
+            #[derive(From)]
+            #[From(all)]
+            enum Enum {
+                First,
+                Second(std::io::Error),
+                Third {
+                    first: String,
+                    second: usize,
+                },
+                #[From(skip)]
+                Fourth(String),
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_one_field() {
+    #[derive(From)]
+    struct Meters {
+        value: f64,
+    }
+
+    assert!(Meters::from(1.5).value == 1.5);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_named_variant_with_one_field() {
+    #[derive(From)]
+    enum Enum {
+        #[From]
+        Case { value: String },
+    }
+
+    assert!(matches!(
+        Enum::from(String::from("foo")),
+        Enum::Case { value } if value == "foo"
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_named_variant_with_default_field() {
+    #[derive(From)]
+    enum Enum {
+        #[From]
+        Io {
+            source: std::io::Error,
+            #[From(default)]
+            context: Vec<String>,
+        },
+    }
+
+    match Enum::from(std::io::Error::other("foo")) {
+        Enum::Io { source, context } => {
+            assert!(source.to_string() == "foo");
+            assert!(context.is_empty());
+        }
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_value_field() {
+    #[derive(From)]
+    struct Struct {
+        first: String,
+        #[From(value = 42)]
+        second: usize,
+        third: bool,
+    }
+
+    let value = Struct::from((String::from("foo"), true));
+
+    assert!(value.first == "foo" && value.second == 42 && value.third);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct_with_default_field() {
+    #[derive(From)]
+    struct TupleStruct(#[From(default)] usize, String);
+
+    let value = TupleStruct::from(String::from("foo"));
+
+    assert!(value.0 == 0 && value.1 == "foo");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "Unknown attribute")]
+fn struct_with_unknown_field_attribute() {
+    Macro::handle(quote!(
+        #[derive(From)]
+        struct Struct {
+            #[From(foo)]
+            first: usize,
+        }
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_default_field() {
+    assert_expansion(
+        "struct_with_default_field",
+        Macro::handle(quote!(
+            #[derive(From)]
+            struct Struct {
+                first: String,
+                #[From(default)]
+                second: usize,
+                #[From(value = String::from("third"))]
+                third: String,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct_with_into() {
+    #[derive(From)]
+    #[From(into)]
+    struct Name(String);
+
+    assert!(Name::from("foo").0 == "foo");
+    assert!(Name::from(String::from("bar")).0 == "bar");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct_with_into() {
+    #[derive(From)]
+    #[From(into)]
+    struct Names<T> {
+        value: Vec<T>,
+    }
+
+    assert!(Names::<u8>::from([1, 2]).value == [1, 2]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_variant_with_into() {
+    #[derive(From)]
+    enum Enum {
+        #[From(into)]
+        Message(String),
+    }
+
+    assert!(matches!(Enum::from("foo"), Enum::Message(value) if value == "foo"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_into_and_other_variant() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        enum Enum {
+            #[From(into)]
+            Message(String),
+            #[From]
+            Io(std::io::Error),
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("compile_error"));
+    assert!(output.contains("Variant `Message` uses `#[From(into)]` which conflicts with `Io`"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_two_intos() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(into)]
+        enum Enum {
+            #[From]
+            First(String),
+            #[From]
+            Second(Vec<u8>),
+            Skipped(usize),
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("Variant `First` uses `#[From(into)]` which conflicts with `Second`"));
+    assert!(output.contains("Variant `Second` uses `#[From(into)]` which conflicts with `First`"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_into_and_struct() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(into, struct = Other)]
+        struct Struct {
+            value: String,
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("`#[From(into)]` conflicts with `#[From(struct = ...)]`"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_rename_and_without_struct() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        struct Struct {
+            #[From(rename = "other")]
+            value: String,
+        }
+    ))
+    .to_string();
+
+    assert!(
+        output.contains("`#[From(rename)]` and `#[From(with)]` require `#[From(struct = ...)]`")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_with_field() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        enum Enum {
+            First(#[From(with = String::from)] String),
+        }
+    ))
+    .to_string();
+
+    assert!(
+        output.contains("`#[From(rename)]` and `#[From(with)]` require `#[From(struct = ...)]`")
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct_with_reflexive_into() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(into)]
+        struct Wrapper<T>(Box<T>);
+    ))
+    .to_string();
+
+    assert!(output.contains("compile_error"));
+    assert!(output.contains("impl<T> From<T> for T"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_into_and_two_fields() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(into)]
+        struct Struct(String, String);
+    ))
+    .to_string();
+
+    assert!(output.contains("requires exactly one source field"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_into() {
+    assert_expansion(
+        "struct_with_into",
+        Macro::handle(quote!(
+            #[derive(From)]
+            #[From(into)]
+            struct Name<'a> {
+                value: std::borrow::Cow<'a, str>,
+                #[From(default)]
+                length: usize,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct_with_reverse() {
+    #[derive(From)]
+    #[From(reverse)]
+    struct Name(String);
+
+    assert!(String::from(Name::from(String::from("foo"))) == "foo");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_reverse_and_default_field() {
+    #[derive(From)]
+    #[From(reverse)]
+    struct Struct<T> {
+        first: Vec<T>,
+        #[From(default)]
+        _second: usize,
+        third: bool,
+    }
+
+    assert!(<(Vec<u8>, bool)>::from(Struct::from((vec![1], true))) == (vec![1], true));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_reverse() {
+    #[derive(Debug, From)]
+    #[From(reverse)]
+    enum Event {
+        #[From]
+        Message(String),
+        #[From]
+        Move { x: i32, y: i32 },
+        #[From]
+        Close,
+    }
+
+    assert!(String::try_from(Event::from(String::from("foo"))).unwrap() == "foo");
+    assert!(<(i32, i32)>::try_from(Event::from((1, 2))).unwrap() == (1, 2));
+    assert!(matches!(String::try_from(Event::Close), Err(Event::Close)));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_variant_with_reverse() {
+    #[derive(Debug, From)]
+    enum Event {
+        #[From(reverse)]
+        Message(String),
+        _Close,
+    }
+
+    assert!(matches!(
+        String::try_from(Event::_Close),
+        Err(Event::_Close)
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_enum_with_reverse_into_type_parameter() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(reverse)]
+        enum Enum<T> {
+            #[From]
+            First(T),
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("compile_error"));
+    assert!(output.contains("cannot implement a conversion into the type parameter `T`"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct_with_reverse_into_type_parameter() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(reverse)]
+        struct Wrapper<T>(Box<T>);
+    ))
+    .to_string();
+
+    assert!(output.contains("cannot implement a conversion into the type parameter `Box < T >`"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_enum_with_reverse() {
+    #[derive(Debug, From)]
+    #[From(reverse)]
+    enum Enum<T> {
+        #[From]
+        First(Vec<T>),
+        #[From]
+        Second(T, usize),
+    }
+
+    assert!(Vec::<u8>::try_from(Enum::First(vec![1])).unwrap() == [1]);
+    assert!(<(u8, usize)>::try_from(Enum::Second(1u8, 2)).unwrap() == (1, 2));
+    assert!(Vec::<u8>::try_from(Enum::Second(1u8, 2)).is_err());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_enum_with_reverse() {
+    assert_expansion(
+        "enum_with_reverse",
+        Macro::handle(quote!(
+            #[derive(From)]
+            #[From(reverse)]
+            enum Event<T> {
+                #[From]
+                Message(String),
+                #[From]
+                Batch {
+                    values: Vec<T>,
+                    #[From(default)]
+                    length: usize,
+                },
+                #[From]
+                Close,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_duplicate_source_types() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(all)]
+        enum Enum {
+            First(String),
+            Second { value: String },
+            Third(usize),
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("compile_error"));
+    assert!(output.contains("Variants `First` and `Second` both convert from `String`"));
+    assert!(output.contains("#[From(skip)]"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_skipped_duplicate_source_type() {
+    #[derive(From)]
+    #[From(all)]
+    enum Enum {
+        First(String),
+        #[From(skip)]
+        _Second(String),
+    }
+
+    assert!(matches!(Enum::from(String::from("foo")), Enum::First(value) if value == "foo"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_variant_with_via() {
+    #[derive(From)]
+    enum ParseError {
+        #[From]
+        Int(std::num::ParseIntError),
+        #[From]
+        Float(std::num::ParseFloatError),
+    }
+
+    #[derive(From)]
+    enum Error {
+        #[From(via = ParseError)]
+        Parse(ParseError),
+        #[From]
+        Io(std::io::Error),
+    }
+
+    assert!(matches!(
+        Error::from("foo".parse::<u8>().unwrap_err()),
+        Error::Parse(ParseError::Int(error)) if error.to_string().contains("invalid digit")
+    ));
+    assert!(matches!(
+        Error::from("foo".parse::<f32>().unwrap_err()),
+        Error::Parse(ParseError::Float(error)) if error.to_string().contains("invalid float")
+    ));
+    assert!(matches!(
+        Error::from(std::io::Error::other("foo")),
+        Error::Io(error) if error.to_string() == "foo"
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_via() {
+    #[derive(From)]
+    #[From(via = String)]
+    struct Message(Box<str>);
+
+    assert!(&*Message::from('a').0 == "a");
+    assert!(&*Message::from("foo").0 == "foo");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_two_vias() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        enum Enum {
+            #[From(via = String)]
+            First(String),
+            #[From(via = Vec<u8>)]
+            Second(Vec<u8>),
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("Variants `First` and `Second` both use `#[From(via)]`"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_foreign_via_and_other_variant() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        enum Enum {
+            #[From(via = String)]
+            Message(String),
+            #[From]
+            Io(std::io::Error),
+            Skipped(usize),
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("compile_error"));
+    assert!(output.contains("Variant `Message` converts via `String` which conflicts with `Io`"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_foreign_via_only() {
+    #[derive(From)]
+    enum Enum {
+        #[From(via = std::string::String)]
+        Message(Box<str>),
+        _Skipped(usize),
+    }
+
+    assert!(matches!(Enum::from('a'), Enum::Message(value) if &*value == "a"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_foreign_via_and_struct() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(via = Vec<u8>, struct = Other)]
+        struct Struct {
+            value: Box<[u8]>,
+        }
+    ))
+    .to_string();
+
+    assert!(output
+        .contains("`#[From(via)]` next to `#[From(struct = ...)]` requires a type of this crate"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_enum_with_via() {
+    assert_expansion(
+        "enum_with_via",
+        Macro::handle(quote!(
+            #[derive(From)]
+            enum Error {
+                #[From(via = ParseError)]
+                Parse(ParseError),
+                #[From]
+                Io(std::io::Error),
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_ref() {
+    #[derive(From)]
+    #[From(ref)]
+    struct Name(String);
+
+    let name = String::from("foo");
+
+    assert!(Name::from(&name).0 == "foo");
+    assert!(Name::from(name).0 == "foo");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct_with_ref() {
+    #[derive(From)]
+    #[From(ref)]
+    struct Wrapper<T>(T);
+
+    let name = String::from("foo");
+
+    assert!(Wrapper::<String>::from(&name).0 == "foo");
+    assert!(Wrapper::from(name).0 == "foo");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_variant_with_ref_and_two_fields() {
+    #[derive(From)]
+    enum Enum {
+        #[From(ref)]
+        Case(String, usize),
+    }
+
+    let value = (String::from("foo"), 1);
+
+    assert!(matches!(Enum::from(&value), Enum::Case(first, 1) if first == "foo"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_variant_with_boxed() {
+    struct Inner {
+        value: usize,
+    }
+
+    #[derive(From)]
+    enum Error {
+        #[From(boxed)]
+        Large(Box<Inner>),
+    }
+
+    assert!(matches!(Error::from(Inner { value: 1 }), Error::Large(inner) if inner.value == 1));
+    assert!(matches!(
+        Error::from(Box::new(Inner { value: 2 })),
+        Error::Large(inner) if inner.value == 2
+    ));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_boxed_without_box() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(boxed)]
+        struct Struct(String);
+    ))
+    .to_string();
+
+    assert!(output.contains("requires a `Box<T>` field"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_boxed_duplicate_source_type() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        enum Enum {
+            #[From]
+            First(String),
+            #[From(boxed)]
+            Second(Box<String>),
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("Variants `First` and `Second` both convert from `String`"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_enum_with_ref_and_boxed() {
+    assert_expansion(
+        "enum_with_ref_and_boxed",
+        Macro::handle(quote!(
+            #[derive(From)]
+            enum Error<T> {
+                #[From(ref)]
+                Message(String),
+                #[From(boxed)]
+                Large(Box<Vec<T>>),
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_repr() {
+    #[derive(Debug, PartialEq, From)]
+    #[From(repr)]
+    #[repr(u8)]
+    enum Opcode {
+        Ping = 1,
+        Pong,
+        Close = 8,
+    }
+
+    assert!(Opcode::try_from(1) == Ok(Opcode::Ping));
+    assert!(Opcode::try_from(2) == Ok(Opcode::Pong));
+    assert!(Opcode::try_from(8) == Ok(Opcode::Close));
+    assert!(Opcode::try_from(3) == Err(OpcodeReprError(3)));
+    assert!(OpcodeReprError(3).to_string() == "Unknown Opcode discriminant 3");
+    assert!(u8::from(Opcode::Close) == 8);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_repr_and_alignment() {
+    #[derive(Debug, PartialEq, From)]
+    #[From(repr)]
+    #[repr(i16, align(4))]
+    enum Level {
+        Low = -1,
+        High = 1,
+    }
+
+    assert!(Level::try_from(-1) == Ok(Level::Low));
+    assert!(i16::from(Level::High) == 1);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_repr_without_integer_repr() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(repr)]
+        enum Enum {
+            First,
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("requires an integer `#[repr]`"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn enum_with_repr_and_fields() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(repr)]
+        #[repr(u8)]
+        enum Enum {
+            First(u8),
+        }
+    ))
+    .to_string();
+
+    assert!(output.contains("requires unit variants"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_enum_with_repr() {
+    assert_expansion(
+        "enum_with_repr",
+        Macro::handle(quote!(
+            #[derive(From)]
+            #[From(repr)]
+            #[repr(u16)]
+            pub enum Opcode {
+                Ping = 1,
+                Pong = 2,
+            }
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct_with_iter() {
+    #[derive(From)]
+    #[From(iter)]
+    struct Names(Vec<String>);
+
+    let mut names = ["foo", "bar"]
+        .into_iter()
+        .map(String::from)
+        .collect::<Names>();
+
+    names.extend([String::from("baz")]);
+
+    for name in &mut names {
+        name.push('!');
+    }
+
+    assert!((&names).into_iter().count() == 3);
+    assert!(names.into_iter().collect::<Vec<_>>() == ["foo!", "bar!", "baz!"]);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn generic_struct_with_iter() {
+    #[derive(From)]
+    #[From(iter)]
+    struct Index<K, V> {
+        values: std::collections::BTreeMap<K, V>,
+        #[From(default)]
+        generation: usize,
+    }
+
+    let index = [(1, "foo"), (2, "bar")]
+        .into_iter()
+        .collect::<Index<_, _>>();
+
+    assert!(index.generation == 0);
+    assert!(index.into_iter().map(|(key, _value)| key).sum::<u8>() == 3);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_iter() {
+    assert_expansion(
+        "struct_with_iter",
+        Macro::handle(quote!(
+            #[derive(From)]
+            #[From(iter)]
+            struct Names<T>(Vec<T>);
+        )),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn struct_with_struct() {
+    struct UserDto {
+        name: String,
+        mail: String,
+        age: u32,
+        _internal: bool,
+    }
+
+    #[derive(From)]
+    #[From(struct = UserDto)]
+    struct User {
+        name: String,
+        #[From(rename = "mail")]
+        email: String,
+        #[From(with = u64::from)]
+        age: u64,
+        #[From(default)]
+        groups: Vec<String>,
+    }
+
+    let user = User::from(UserDto {
+        name: String::from("foo"),
+        mail: String::from("foo@example.com"),
+        age: 42,
+        _internal: true,
+    });
+
+    assert!(user.name == "foo");
+    assert!(user.email == "foo@example.com");
+    assert!(user.age == 42);
+    assert!(user.groups.is_empty());
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn tuple_struct_with_struct() {
+    let output = Macro::handle(quote!(
+        #[derive(From)]
+        #[From(struct = Other)]
+        struct Struct(String);
+    ))
+    .to_string();
+
+    assert!(output.contains("requires named fields"));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct_with_struct() {
+    assert_expansion(
+        "struct_with_struct",
+        Macro::handle(quote!(
+            #[derive(From)]
+            #[From(struct = UserDto)]
+            struct User {
+                name: String,
+                #[From(rename = "mail")]
+                email: String,
+                #[From(with = u64::from)]
+                age: u64,
+                #[From(default)]
+                groups: Vec<String>,
+            }
+        )),
+    );
+}
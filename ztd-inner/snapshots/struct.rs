@@ -0,0 +1,12 @@
+pub struct StructInner {
+    pub first: String,
+    pub second: usize,
+}
+impl Struct {
+    pub fn into_inner(self) -> StructInner {
+        StructInner {
+            first: self.first,
+            second: self.second,
+        }
+    }
+}
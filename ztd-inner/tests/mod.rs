@@ -2,6 +2,7 @@ use quote::quote;
 use trybuild::TestCases;
 use ztd_inner::Inner;
 use ztd_inner_macro::Macro;
+use ztd_test::assert_expansion;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -145,3 +146,19 @@ fn ui() {
     let cases = TestCases::new();
     cases.compile_fail("ui/struct_skip.rs")
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct() {
+    assert_expansion(
+        "struct",
+        Macro::handle(quote!(
+            #[derive(Inner)]
+            pub struct Struct {
+                first: String,
+                second: usize,
+            }
+        )),
+    );
+}
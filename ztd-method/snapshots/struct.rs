@@ -0,0 +1,26 @@
+impl Struct {
+    pub fn first(&self) -> &String {
+        &self.first
+    }
+    pub fn second(&self) -> usize {
+        self.second
+    }
+    pub fn third(&self) -> bool {
+        self.third
+    }
+    pub fn first_mut(&mut self) -> &mut String {
+        &mut self.first
+    }
+    pub fn second_mut(&mut self) -> &mut usize {
+        &mut self.second
+    }
+    pub fn third_mut(&mut self) -> &mut bool {
+        &mut self.third
+    }
+    pub fn set_first(&mut self, first: String) {
+        self.first = first;
+    }
+    pub fn set_second(&mut self, second: usize) {
+        self.second = second;
+    }
+}
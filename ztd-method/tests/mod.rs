@@ -2,6 +2,7 @@ use quote::quote;
 use trybuild::TestCases;
 use ztd_method::Method;
 use ztd_method_macro::Macro;
+use ztd_test::assert_expansion;
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
@@ -537,3 +538,22 @@ fn ui() {
     cases.compile_fail("ui/struct_skip_mutator.rs");
     cases.compile_fail("ui/struct_skip_setter.rs");
 }
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn snapshot_struct() {
+    assert_expansion(
+        "struct",
+        Macro::handle(quote!(
+            #[derive(Method)]
+            #[Method(all)]
+            pub struct Struct {
+                first: String,
+                second: usize,
+                #[Method(skip_setter)]
+                third: bool,
+            }
+        )),
+    );
+}
@@ -0,0 +1,5 @@
+impl Struct {
+    fn new() -> Self {
+        Self
+    }
+}
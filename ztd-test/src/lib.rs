@@ -0,0 +1,101 @@
+use proc_macro2::TokenStream;
+use std::env;
+use std::fmt::Write;
+use std::fs;
+use std::path::PathBuf;
+use syn::{parse2, File};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn expand(stream: TokenStream) -> String {
+    match parse2::<File>(stream) {
+        Ok(file) => prettyplease::unparse(&file),
+        Err(error) => panic!("Invalid expansion: {}", error),
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+fn write_diff(expected: &str, actual: &str) -> String {
+    let expected = expected.lines().collect::<Vec<_>>();
+    let actual = actual.lines().collect::<Vec<_>>();
+
+    let mut lengths = vec![vec![0usize; actual.len() + 1]; expected.len() + 1];
+
+    for (expected_index, expected_line) in expected.iter().enumerate().rev() {
+        for (actual_index, actual_line) in actual.iter().enumerate().rev() {
+            lengths[expected_index][actual_index] = if expected_line == actual_line {
+                lengths[expected_index + 1][actual_index + 1] + 1
+            } else {
+                lengths[expected_index + 1][actual_index]
+                    .max(lengths[expected_index][actual_index + 1])
+            };
+        }
+    }
+
+    let mut diff = String::new();
+    let (mut expected_index, mut actual_index) = (0, 0);
+
+    while expected_index < expected.len() || actual_index < actual.len() {
+        if expected_index < expected.len()
+            && actual_index < actual.len()
+            && expected[expected_index] == actual[actual_index]
+        {
+            let _ = writeln!(diff, "  {}", expected[expected_index]);
+            expected_index += 1;
+            actual_index += 1;
+        } else if expected_index < expected.len()
+            && (actual_index == actual.len()
+                || lengths[expected_index + 1][actual_index]
+                    >= lengths[expected_index][actual_index + 1])
+        {
+            let _ = writeln!(diff, "- {}", expected[expected_index]);
+            expected_index += 1;
+        } else {
+            let _ = writeln!(diff, "+ {}", actual[actual_index]);
+            actual_index += 1;
+        }
+    }
+
+    diff
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn assert_snapshot(name: &str, actual: &str) {
+    let directory = match env::var_os("CARGO_MANIFEST_DIR") {
+        Some(directory) => PathBuf::from(directory).join("snapshots"),
+        None => panic!("Missing CARGO_MANIFEST_DIR"),
+    };
+
+    let path = directory.join(format!("{}.rs", name));
+    let overwrite = env::var("ZTD_SNAPSHOT").is_ok_and(|value| value == "overwrite");
+
+    let expected = match fs::read_to_string(&path) {
+        Ok(expected) if !overwrite => expected,
+        result => {
+            fs::create_dir_all(&directory).expect("Writable snapshot directory");
+            fs::write(&path, actual).expect("Writable snapshot");
+
+            if result.is_err() && !overwrite {
+                panic!("Snapshot {} written, review it and rerun", path.display())
+            }
+
+            return;
+        }
+    };
+
+    if expected != actual {
+        panic!(
+            "Snapshot {} does not match, rerun with ZTD_SNAPSHOT=overwrite to update it\n\n{}",
+            path.display(),
+            write_diff(&expected, actual),
+        )
+    }
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+pub fn assert_expansion(name: &str, stream: TokenStream) {
+    assert_snapshot(name, &expand(stream))
+}
@@ -0,0 +1,42 @@
+use quote::quote;
+use ztd_test::{assert_expansion, assert_snapshot, expand};
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn expansion() {
+    assert!(
+        expand(quote!(
+            struct Struct;
+        )) == "struct Struct;\n"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn matching_snapshot() {
+    assert_expansion(
+        "matching",
+        quote!(
+            impl Struct {
+                fn new() -> Self {
+                    Self
+                }
+            }
+        ),
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+#[should_panic(expected = "- struct First;\n+ struct Second;")]
+fn mismatching_snapshot() {
+    assert_snapshot(
+        "mismatching",
+        &expand(quote!(
+            struct Second;
+        )),
+    );
+}
@@ -2,7 +2,11 @@
 
 ////////////////////////////////////////////////////////////////////////////////////////////////////
 
+pub use ztd_builder::*;
 pub use ztd_constructor::*;
+/// Renamed to not clash with the `Debug` derive of the prelude.
+pub use ztd_debug::Debug as ZtdDebug;
+pub use ztd_debug::{Truncate, Truncated, With};
 pub use ztd_display::*;
 pub use ztd_error::*;
 pub use ztd_from::*;
@@ -0,0 +1,116 @@
+use ztd::*;
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn debug() {
+    #[derive(Debug)]
+    struct Prelude {
+        _first: usize,
+    }
+
+    #[derive(ZtdDebug)]
+    struct Struct {
+        _first: usize,
+        #[Debug(skip)]
+        _second: usize,
+    }
+
+    assert!(format!("{:?}", Prelude { _first: 1 }) == "Prelude { _first: 1 }");
+    assert!(
+        format!(
+            "{:?}",
+            Struct {
+                _first: 1,
+                _second: 2
+            }
+        ) == "Struct { _first: 1, .. }"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn debug_with_and_truncate() {
+    fn write_hidden(_value: &usize, formatter: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        formatter.write_str("hidden")
+    }
+
+    #[derive(ZtdDebug)]
+    struct Struct {
+        #[Debug(with = write_hidden)]
+        _first: usize,
+        #[Debug(truncate = 3)]
+        _second: &'static str,
+    }
+
+    assert!(
+        format!(
+            "{:?}",
+            Struct {
+                _first: 1,
+                _second: "foobar"
+            }
+        ) == "Struct { _first: hidden, _second: \"foo\"... }"
+    );
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn display_key() {
+    #[derive(Display)]
+    #[Display("{path} not found")]
+    #[Display(key = "errors.not_found")]
+    struct Struct {
+        path: &'static str,
+    }
+
+    assert!(Struct { path: "foo" }.message_key() == "errors.not_found");
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn builder() {
+    #[derive(Builder)]
+    struct Struct {
+        first: usize,
+    }
+
+    assert!(Struct::builder().first(1usize).build().unwrap().first == 1);
+    assert!(Struct::builder().build().err() == Some(MissingFieldError::new("first")));
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn typestate_builder() {
+    #[derive(Builder)]
+    #[Builder(typestate)]
+    struct Struct {
+        first: usize,
+        #[Builder(default)]
+        second: usize,
+    }
+
+    let value = Struct::builder().first(1usize).build();
+
+    assert!(value.first == 1);
+    assert!(value.second == 0);
+}
+
+////////////////////////////////////////////////////////////////////////////////////////////////////
+
+#[test]
+fn constructor_wrap() {
+    #[derive(Constructor)]
+    #[Constructor(wrap(Arc, Rc, Box))]
+    struct Struct {
+        first: usize,
+    }
+
+    assert!(Struct::new_arc(1).first == 1);
+    assert!(Struct::new_rc(1).first == 1);
+    assert!(Struct::new_boxed(1).first == 1);
+}